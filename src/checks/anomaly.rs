@@ -0,0 +1,86 @@
+use std::{fmt::Display, sync::Mutex, time::Duration};
+
+use crate::shared::{
+    alert::{Alert, Metadata, Severity},
+    anomaly::{AnomalyDetector, SeasonalHistory, Series},
+};
+
+use super::{AppConfig, CheckTrait};
+
+const ANOMALY_CHECK_ID: &str = "anomaly_check";
+
+pub struct AnomalyCheck {
+    detectors: Vec<(Series, AnomalyDetector, Mutex<SeasonalHistory>)>,
+}
+
+impl AnomalyCheck {
+    pub fn new(config: &AppConfig) -> Self {
+        let detectors = config
+            .get_config()
+            .anomalies
+            .iter()
+            .map(|anomaly| {
+                (
+                    anomaly.series,
+                    anomaly.detector(),
+                    Mutex::new(SeasonalHistory::default()),
+                )
+            })
+            .collect();
+        Self { detectors }
+    }
+}
+
+#[async_trait::async_trait]
+impl CheckTrait for AnomalyCheck {
+    async fn check(&self, state: &super::State) -> Vec<Alert> {
+        let last_state = state.last_block();
+
+        self.detectors
+            .iter()
+            .filter_map(|(series, detector, history)| {
+                let points = series.points(state);
+                let anomaly = {
+                    let mut history = history.lock().unwrap();
+                    let (timestamp, value) = *points.last()?;
+                    let anomaly = detector.evaluate(&points, Some(history.hour(timestamp)));
+                    history.record(last_state.block.height, timestamp, value);
+                    anomaly?
+                };
+                let direction = if anomaly.z_score > 0.0 {
+                    "above"
+                } else {
+                    "below"
+                };
+
+                Some(Alert {
+                    check_id: format!("{}_{}", ANOMALY_CHECK_ID, series),
+                    title: format!("Anomalous {} in block {}", series, last_state.block.height),
+                    description: format!(
+                        "*{}* is *{:.2}*, {:.1} standard deviations {} the baseline of {:.2} (σ {:.2}).",
+                        series,
+                        anomaly.value,
+                        anomaly.z_score.abs(),
+                        direction,
+                        anomaly.mean,
+                        anomaly.std_dev
+                    ),
+                    metadata: Metadata::new(Some(last_state.block.height as u32), None),
+                    severity: Severity::Medium,
+                    trigger_after: Some(Duration::from_secs(30 * 60)),
+                    continous: self.is_continous(),
+                })
+            })
+            .collect()
+    }
+
+    fn is_continous(&self) -> bool {
+        false
+    }
+}
+
+impl Display for AnomalyCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AnomalyCheck with {} series", self.detectors.len())
+    }
+}
@@ -0,0 +1,61 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::shared::alert::{Alert, Metadata, Severity};
+
+use super::CheckTrait;
+
+const DECODE_CHECK_ID: &str = "decode_check";
+
+const LOOKBACK_WINDOW: usize = 100;
+
+#[derive(Default)]
+pub struct DecodeCheck {}
+
+#[async_trait::async_trait]
+impl CheckTrait for DecodeCheck {
+    async fn check(&self, state: &super::State) -> Vec<Alert> {
+        let last_state = state.last_block();
+        if last_state.block.decode_errors.is_empty() {
+            return vec![];
+        }
+
+        let window_size = state.blocks.len().min(LOOKBACK_WINDOW);
+        let total_errors = state.blocks[state.blocks.len() - window_size..]
+            .iter()
+            .map(|block_state| block_state.block.decode_errors.len())
+            .sum::<usize>();
+
+        vec![Alert {
+            check_id: DECODE_CHECK_ID.to_string(),
+            title: "Undecodable block data".to_string(),
+            description: format!(
+                "Block {} has *{}* undecodable pieces ({}), *{}* over the last {} blocks. The node may emit data the monitor does not understand",
+                last_state.block.height,
+                last_state.block.decode_errors.len(),
+                last_state
+                    .block
+                    .decode_errors
+                    .iter()
+                    .map(|error| error.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                total_errors,
+                window_size
+            ),
+            severity: Severity::High,
+            metadata: Metadata::new(Some(last_state.block.height as u32), None),
+            trigger_after: Some(Duration::from_secs(60 * 60)),
+            continous: self.is_continous(),
+        }]
+    }
+
+    fn is_continous(&self) -> bool {
+        false
+    }
+}
+
+impl Display for DecodeCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DecodeCheck(lookback_window: {})", LOOKBACK_WINDOW)
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{
-    constants::MICRO,
-    shared::alert::{Alert, Metadata, Severity},
+    shared::{
+        alert::{Alert, Metadata, Severity},
+        token,
+    },
     state::State,
 };
 
@@ -11,7 +13,6 @@ const FEE_CHECK_ID: &str = "fee_check";
 
 #[derive(Debug, Clone)]
 struct DefaultFeeThreshold {
-    name: String,
     value: f64,
 }
 
@@ -23,13 +24,8 @@ pub struct FeeCheck {
 impl FeeCheck {
     fn populate_thresholds(&mut self, config: &AppConfig) {
         for (token, threshold) in config.get_config().tokens_fees() {
-            self.thresholds.insert(
-                token.clone(),
-                DefaultFeeThreshold {
-                    name: token,
-                    value: threshold * MICRO,
-                },
-            );
+            self.thresholds
+                .insert(token, DefaultFeeThreshold { value: threshold });
         }
     }
 
@@ -50,7 +46,7 @@ impl CheckTrait for FeeCheck {
         last_state.block.transactions.iter().filter_map(|tx| {
             let tx_id = tx.id.clone();
             let fee_token = tx.fee.gas_token.clone();
-            let fee = tx.compute_fee();
+            let fee = token::to_f64(&tx.fee.charged_fee()?);
 
             match self.thresholds.get(&fee_token) {
                 Some(threshold) => {
@@ -58,7 +54,8 @@ impl CheckTrait for FeeCheck {
                         None
                     } else {
                         let title = "Fee too high".to_string();
-                        let description = format!("The transaction *{}* paid a fee of *{}* _{}_, which is above the threshold of *{}* _{}_.", tx_id, fee, threshold.name, threshold.value, threshold.name);
+                        let token_name = state.token_name(&fee_token);
+                        let description = format!("The transaction *{}* charged *{}* a fee of *{}* _{}_, which is above the threshold of *{}* _{}_.", tx_id, tx.fee.gas_payer, fee, token_name, threshold.value, token_name);
                         Some(Alert {
                             check_id: FEE_CHECK_ID.to_string(),
                             title,
@@ -20,7 +20,7 @@ impl CheckTrait for GasCheck {
             .transactions
             .iter()
             .filter_map(|transaction| {
-                let gas_limit = transaction.fee.gas.parse::<f64>().unwrap_or_default();
+                let gas_limit = transaction.fee.gas_limit as f64;
                 let gas_used = transaction.fee.gas_used as f64;
 
                 if gas_used * (1.0 + self.gas_limit_threshold) < gas_limit {
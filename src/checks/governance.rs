@@ -0,0 +1,153 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::shared::{
+    alert::{Alert, Metadata, Severity},
+    governance::Proposal,
+};
+
+use super::CheckTrait;
+
+const GOVERNANCE_CHECK_ID: &str = "governance_check";
+
+/// Warn when the voting period of a proposal ends within this many epochs
+const VOTING_END_WARNING_EPOCHS: u64 = 1;
+
+#[derive(Default)]
+pub struct GovernanceCheck {}
+
+impl GovernanceCheck {
+    fn alert(
+        &self,
+        event: &str,
+        proposal: &Proposal,
+        height: u64,
+        severity: Severity,
+        trigger_after: Option<Duration>,
+    ) -> impl FnOnce(String, String) -> Alert {
+        let check_id = format!("{}_{}_{}", GOVERNANCE_CHECK_ID, event, proposal.id);
+        let continous = self.is_continous();
+        move |title, description| Alert {
+            check_id,
+            title,
+            description,
+            metadata: Metadata::new(Some(height as u32), None),
+            severity,
+            trigger_after,
+            continous,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CheckTrait for GovernanceCheck {
+    async fn check(&self, state: &super::State) -> Vec<Alert> {
+        let last_state = state.last_block();
+        let prev_state = state.prev_block();
+        let epoch = last_state.block.epoch;
+        let height = last_state.block.height;
+        let new_epoch = epoch != prev_state.block.epoch;
+
+        let mut alerts = Vec::new();
+        for proposal in last_state.governance.proposals.values() {
+            let code = match &proposal.code_hash {
+                Some(hash) => format!(" carrying wasm code *{}*", hash),
+                None => String::new(),
+            };
+
+            if proposal.id >= prev_state.governance.next_id {
+                let severity = if proposal.has_code() {
+                    Severity::High
+                } else {
+                    Severity::Medium
+                };
+                let alert = self.alert("created", proposal, height, severity, None);
+                alerts.push(alert(
+                    format!("New governance proposal #{}", proposal.id),
+                    format!(
+                        "Proposal *#{}* ({}){} was submitted by {}. Voting runs from epoch {} to {}, activation at epoch {}",
+                        proposal.id,
+                        proposal.kind,
+                        code,
+                        proposal.author,
+                        proposal.voting_start_epoch,
+                        proposal.voting_end_epoch,
+                        proposal.activation_epoch
+                    ),
+                ));
+            }
+
+            if new_epoch && proposal.voting_start_epoch == epoch {
+                let alert = self.alert("voting_open", proposal, height, Severity::Medium, None);
+                alerts.push(alert(
+                    format!("Voting opened for proposal #{}", proposal.id),
+                    format!(
+                        "Voting for proposal *#{}* ({}){} is open until epoch {}",
+                        proposal.id, proposal.kind, code, proposal.voting_end_epoch
+                    ),
+                ));
+            }
+
+            if proposal.is_voting(epoch)
+                && proposal.voting_end_epoch - epoch <= VOTING_END_WARNING_EPOCHS
+            {
+                let tally = match &proposal.tally {
+                    Some(tally) => format!(
+                        " Current tally: {:.2}% yay, {:.2}% nay, {:.2}% abstain, {}.",
+                        tally.ratio(tally.yay) * 100.0,
+                        tally.ratio(tally.nay) * 100.0,
+                        tally.ratio(tally.abstain) * 100.0,
+                        if tally.passed {
+                            "passing"
+                        } else {
+                            "not passing"
+                        }
+                    ),
+                    None => String::new(),
+                };
+                let alert = self.alert(
+                    "voting_end",
+                    proposal,
+                    height,
+                    Severity::Medium,
+                    Some(Duration::from_secs(60 * 60 * 12)),
+                );
+                alerts.push(alert(
+                    format!("Voting for proposal #{} ends soon", proposal.id),
+                    format!(
+                        "Voting for proposal *#{}* ({}){} ends at epoch {} (current epoch {}).{}",
+                        proposal.id, proposal.kind, code, proposal.voting_end_epoch, epoch, tally
+                    ),
+                ));
+            }
+
+            if proposal.has_code() && proposal.passed() && proposal.is_pending_activation(epoch) {
+                let alert = self.alert(
+                    "execution",
+                    proposal,
+                    height,
+                    Severity::High,
+                    Some(Duration::from_secs(60 * 60 * 24)),
+                );
+                alerts.push(alert(
+                    format!("Proposal #{} will execute code", proposal.id),
+                    format!(
+                        "Proposal *#{}* passed and its wasm code{} will be executed at epoch {} (current epoch {})",
+                        proposal.id, code, proposal.activation_epoch, epoch
+                    ),
+                ));
+            }
+        }
+
+        alerts
+    }
+
+    fn is_continous(&self) -> bool {
+        false
+    }
+}
+
+impl Display for GovernanceCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GovernanceCheck")
+    }
+}
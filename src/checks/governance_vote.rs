@@ -0,0 +1,91 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::shared::alert::{Alert, Metadata, Severity};
+
+use super::{AppConfig, CheckTrait};
+
+const GOVERNANCE_VOTE_CHECK_ID: &str = "governance_vote_check";
+
+pub struct GovernanceVoteCheck {
+    validators: Vec<(String, String)>,
+}
+
+impl GovernanceVoteCheck {
+    pub fn new(config: &AppConfig) -> Self {
+        let config = config.get_config();
+        Self {
+            validators: config.validators(),
+        }
+    }
+
+    /// Escalate as the last epoch validators can vote in gets closer
+    fn severity(epochs_left: u64) -> Severity {
+        match epochs_left {
+            0..=1 => Severity::Critical,
+            2 => Severity::High,
+            _ => Severity::Medium,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CheckTrait for GovernanceVoteCheck {
+    async fn check(&self, state: &super::State) -> Vec<Alert> {
+        let last_state = state.last_block();
+        let epoch = last_state.block.epoch;
+
+        let mut alerts = Vec::new();
+        for proposal in last_state.governance.proposals.values() {
+            if !proposal.can_validators_vote(epoch) {
+                continue;
+            }
+            let voting_end_epoch = proposal.validator_voting_end_epoch();
+            let epochs_left = voting_end_epoch - epoch;
+
+            for (alias, address) in &self.validators {
+                if proposal.voters.contains(address) {
+                    continue;
+                }
+
+                let severity = Self::severity(epochs_left);
+                alerts.push(Alert {
+                    // a new id per severity so that escalations are sent right away
+                    check_id: format!(
+                        "{}_{}_{}_{:?}",
+                        GOVERNANCE_VOTE_CHECK_ID, proposal.id, address, severity
+                    ),
+                    title: format!("Validator {} has not voted on proposal #{}", alias, proposal.id),
+                    description: format!(
+                        "Validator *{}* ({}) has not voted on proposal *#{}* ({}). Validators can vote until the end of epoch {}, {} epoch(s) left",
+                        alias,
+                        address,
+                        proposal.id,
+                        proposal.kind,
+                        voting_end_epoch - 1,
+                        epochs_left
+                    ),
+                    metadata: Metadata::new(Some(last_state.block.height as u32), None),
+                    severity,
+                    trigger_after: Some(Duration::from_secs(60 * 60 * 6)),
+                    continous: self.is_continous(),
+                });
+            }
+        }
+
+        alerts
+    }
+
+    fn is_continous(&self) -> bool {
+        false
+    }
+}
+
+impl Display for GovernanceVoteCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GovernanceVoteCheck({} validators)",
+            self.validators.len()
+        )
+    }
+}
@@ -1,136 +1,147 @@
+use std::collections::HashMap;
 use std::fmt::Display;
-use std::str::FromStr;
 use std::time::Duration;
 
-use namada_sdk::ibc::context::client::{AnyClientState, AnyConsensusState};
-use namada_sdk::ibc::core::host::types::path::ClientConsensusStatePath;
-use namada_sdk::ibc::core::host::types::{identifiers::ClientId, path::ClientStatePath};
-use namada_sdk::ibc::primitives::proto::Any;
-use namada_sdk::ibc::storage;
-use namada_sdk::rpc;
-use prost::Message;
-use tendermint_rpc::HttpClient;
-
-use crate::shared::alert::Metadata;
+use crate::shared::alert::{Alert, Metadata, Severity};
 
 use super::{AppConfig, CheckTrait};
 
-const HEALTHY_IBC_THRESHOLD: i64 = 60 * 60 * 24 * 2; // 2 days
-
 const IBC_CHECK_ID: &str = "ibc_check";
-
-struct IbcChannel {
-    pub channel_id: u64,
-    pub connection_id: u64,
-    pub client_id: u64,
-    pub alias: String,
+const IBC_CHANNEL_CHECK_ID: &str = "ibc_channel_check";
+const IBC_CONNECTION_CHECK_ID: &str = "ibc_connection_check";
+const IBC_RELAYER_CHECK_ID: &str = "ibc_relayer_check";
+const IBC_PACKET_CHECK_ID: &str = "ibc_packet_check";
+
+struct IbcThresholds {
+    /// Alert when the client expires within this many seconds
+    expiry_horizon: i64,
+    /// Alert when the client was not updated for this many seconds
+    max_idle: i64,
+    /// Alert when a packet is pending for more than this many seconds
+    max_packet_age: i64,
 }
 
 pub struct IbcCheck {
-    channels: Vec<IbcChannel>,
-    rpc: String,
+    thresholds: HashMap<String, IbcThresholds>,
 }
 
 #[async_trait::async_trait]
 impl CheckTrait for IbcCheck {
-    async fn check(&self, _state: &crate::state::State) -> Vec<crate::shared::alert::Alert> {
+    async fn check(&self, state: &crate::state::State) -> Vec<Alert> {
         let mut alerts = vec![];
-
-        let client = HttpClient::new(self.rpc.as_str()).unwrap();
-        for channel in self.channels.iter() {
-            let client_id = format!("07-tendermint-{}", channel.client_id);
-            let client_state_path =
-                ClientStatePath(ClientId::from_str(&client_id).expect("valid client ID"));
-            let client_state_key = storage::ibc_key(client_state_path.to_string())
-                .expect("the path should be parsable");
-            let client_state_res =
-                rpc::query_storage_value_bytes(&client, &client_state_key, None, false).await;
-
-            let client_state = match client_state_res {
-                Ok((Some(value), _proof)) => {
-                    let any = Any::decode(value.as_slice()).expect("decode Any");
-                    let AnyClientState::Tendermint(client_state) =
-                        AnyClientState::try_from(any).expect("valid client state");
-
-                    client_state.inner().clone()
-                }
-                Ok((None, _proof)) => {
-                    tracing::warn!(
-                        "No IBC client state found for {} - channel {} - connection {} - client {}",
-                        channel.alias,
-                        channel.channel_id,
-                        channel.connection_id,
-                        channel.client_id
-                    );
-                    continue;
-                }
-                Err(e) => {
-                    tracing::error!(
-                        "Failed to query IBC client state for {}: {}",
-                        channel.alias,
-                        e
-                    );
-                    continue;
-                }
+        let Some(last_state) = state.blocks.last() else {
+            return alerts;
+        };
+
+        let now = last_state.block.timestamp;
+        for channel in &last_state.ibc_channels {
+            let Some(thresholds) = self.thresholds.get(&channel.alias) else {
+                continue;
             };
-
-            let client_consensus_state_path = ClientConsensusStatePath {
-                client_id: ClientId::from_str(&client_id).expect("valid client ID"),
-                revision_number: client_state.latest_height.revision_number(),
-                revision_height: client_state.latest_height.revision_height(),
+            let name = match &channel.channel_id {
+                Some(channel_id) => format!(
+                    "{} for *{}* (connection {}, client {})",
+                    channel_id, channel.alias, channel.connection_id, channel.client_id
+                ),
+                None => format!(
+                    "{} for *{}* (client {})",
+                    channel.connection_id, channel.alias, channel.client_id
+                ),
+            };
+            let alert = |check_id: &str, title: &str, description: String, severity| Alert {
+                check_id: format!("{}-{}", check_id, channel.alias),
+                title: title.to_string(),
+                description,
+                severity,
+                metadata: Metadata::new(None, None),
+                trigger_after: Some(Duration::from_secs(60 * 60)),
+                continous: self.is_continous(),
             };
 
-            let client_consensus_key = storage::ibc_key(client_consensus_state_path.to_string())
-                .expect("the path should be parsable");
-            let client_consensus_state_res =
-                rpc::query_storage_value_bytes(&client, &client_consensus_key, None, false).await;
-
-            let client_consensus_state = match client_consensus_state_res {
-                Ok((Some(value), _proof)) => {
-                    let any = Any::decode(value.as_slice()).expect("decode Any");
-                    let AnyConsensusState::Tendermint(client_consensus_state) =
-                        AnyConsensusState::try_from(any).expect("valid client state");
-
-                    client_consensus_state.inner().clone()
+            if channel.channel_id.is_some() && !channel.channel_open {
+                alerts.push(alert(
+                    IBC_CHANNEL_CHECK_ID,
+                    "IBC Channel Not Open",
+                    format!(
+                        "The IBC channel {} is not open: *{}*.",
+                        name,
+                        channel.channel_state.as_deref().unwrap_or("not found")
+                    ),
+                    Severity::High,
+                ));
+            }
+
+            if !channel.connection_open {
+                alerts.push(alert(
+                    IBC_CONNECTION_CHECK_ID,
+                    "IBC Connection Not Open",
+                    format!(
+                        "The IBC connection of {} is not open: *{}*.",
+                        name,
+                        channel.connection_state.as_deref().unwrap_or("not found")
+                    ),
+                    Severity::High,
+                ));
+            }
+
+            match channel.seconds_until_expiry(now) {
+                Some(seconds_left) if seconds_left < thresholds.expiry_horizon => {
+                    alerts.push(alert(
+                        IBC_CHECK_ID,
+                        "IBC Client Expiration",
+                        format!(
+                            "The IBC client of {} is near expiration: *{:.1}* hours left.",
+                            name,
+                            seconds_left as f64 / 3600.0
+                        ),
+                        Severity::High,
+                    ));
                 }
-                Ok((None, _proof)) => {
-                    tracing::warn!(
-                        "No IBC client state found for {} - channel {} - connection {} - client {}",
-                        channel.alias,
-                        channel.channel_id,
-                        channel.connection_id,
-                        channel.client_id
-                    );
-                    continue;
+                None => {
+                    tracing::warn!("No IBC client state found for {}", name);
                 }
-                Err(e) => {
-                    tracing::error!(
-                        "Failed to query IBC client state for {}: {}",
-                        channel.alias,
-                        e
-                    );
-                    continue;
+                _ => {}
+            }
+
+            if let Some(idle) = channel.seconds_since_update(now) {
+                if idle > thresholds.max_idle {
+                    alerts.push(alert(
+                        IBC_RELAYER_CHECK_ID,
+                        "IBC Relayer Idle",
+                        format!(
+                            "The IBC client of {} was last updated *{:.1}* hours ago. Relayers may be down.",
+                            name,
+                            idle as f64 / 3600.0
+                        ),
+                        Severity::Medium,
+                    ));
                 }
-            };
-
-            let expiration_time = client_consensus_state.timestamp.unix_timestamp()
-                + client_state.trusting_period.as_secs() as i64;
-            let expiration_timestamp = expiration_time as f64;
-            let now = chrono::Utc::now().timestamp() as f64;
-
-            if now + HEALTHY_IBC_THRESHOLD as f64 > expiration_timestamp {
-                alerts.push(crate::shared::alert::Alert {
-                    check_id: format!("{}-{}", IBC_CHECK_ID, channel.alias),
-                    title: "IBC Client Expiration".to_string(),
-                    description: format!("The IBC channel {} for *{}* (connection {}, client {}), is near expiration: *{}* seconds left.", channel.channel_id, channel.alias, channel.connection_id, channel.client_id, (expiration_timestamp - now) / 3600.0),
-                    severity: crate::shared::alert::Severity::High,
-                    metadata: Metadata::new(
-                        None, None
+            }
+
+            let stuck =
+                channel.stuck_packets(last_state.block.timestamp, thresholds.max_packet_age);
+            if let Some(oldest) = stuck.iter().map(|(_, age)| *age).max() {
+                let sequences = stuck
+                    .iter()
+                    .take(10)
+                    .map(|(sequence, _)| sequence.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                alerts.push(alert(
+                    IBC_PACKET_CHECK_ID,
+                    "IBC Packets Stuck",
+                    format!(
+                        "*{}* packet(s) sent over channel {} were neither acknowledged nor timed out for more than {:.1} hours (oldest pending for *{:.1}* hours, sequences {}{}).",
+                        stuck.len(),
+                        name,
+                        thresholds.max_packet_age as f64 / 3600.0,
+                        oldest as f64 / 3600.0,
+                        sequences,
+                        if stuck.len() > 10 { ", ..." } else { "" }
                     ),
-                    trigger_after: Some(Duration::from_secs(60 * 60)),
-                    continous: self.is_continous(),
-                });
-            };
+                    Severity::Medium,
+                ));
+            }
         }
 
         alerts
@@ -143,27 +154,28 @@ impl CheckTrait for IbcCheck {
 
 impl Display for IbcCheck {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "IbcCheck with {} channels", self.channels.len())
+        write!(f, "IbcCheck with {} channels", self.thresholds.len())
     }
 }
 
 impl IbcCheck {
     pub fn new(config: &AppConfig) -> Self {
-        let channels = config
+        let thresholds = config
             .get_config()
             .ibcs
             .iter()
-            .map(|ibc| IbcChannel {
-                channel_id: ibc.channel,
-                connection_id: ibc.connection,
-                alias: ibc.alias.clone(),
-                client_id: ibc.client_id,
+            .map(|ibc| {
+                (
+                    ibc.alias.clone(),
+                    IbcThresholds {
+                        expiry_horizon: ibc.expiry_horizon as i64,
+                        max_idle: ibc.max_idle as i64,
+                        max_packet_age: ibc.max_packet_age as i64,
+                    },
+                )
             })
             .collect();
 
-        IbcCheck {
-            channels,
-            rpc: config.rpc.clone(),
-        }
+        IbcCheck { thresholds }
     }
 }
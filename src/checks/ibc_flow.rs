@@ -0,0 +1,93 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{
+    shared::alert::{Alert, Metadata, Severity},
+    state::Window,
+};
+
+use super::{AppConfig, CheckTrait};
+
+const IBC_FLOW_CHECK_ID: &str = "ibc_flow_check";
+
+pub struct IbcFlowCheck {
+    net_outflow_ratio: f64,
+    window: Window,
+}
+
+impl IbcFlowCheck {
+    pub fn new(config: &AppConfig) -> Self {
+        let ibc_flow = &config.get_config().ibc_flow;
+        Self {
+            net_outflow_ratio: ibc_flow.net_outflow_ratio,
+            window: Window::Seconds(ibc_flow.window_seconds),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CheckTrait for IbcFlowCheck {
+    async fn check(&self, state: &super::State) -> Vec<Alert> {
+        let last_state = state.last_block();
+        let mut alerts = vec![];
+
+        for ((channel, token), volume) in state.ibc_volumes(self.window) {
+            // Compare with the mint limit of the token, falling back to its total supply
+            let limit = last_state
+                .ibc_limits
+                .get(&token)
+                .filter(|limit| !limit.mint_limit.is_zero())
+                .map(|limit| ("IBC mint limit", limit.mint_limit));
+            let supply = last_state
+                .supplies
+                .iter()
+                .find(|supply| supply.token == token)
+                .map(|supply| ("total supply", supply.total));
+            let Some((reference_name, reference)) = limit.or(supply) else {
+                continue;
+            };
+
+            let net_outflow = state.to_f64(&token, volume.net_outflow());
+            let reference = state.to_f64(&token, reference);
+            if reference <= 0.0 || net_outflow <= reference * self.net_outflow_ratio {
+                continue;
+            }
+
+            alerts.push(Alert {
+                check_id: format!("{}_{}_{}", IBC_FLOW_CHECK_ID, channel, token),
+                title: format!("IBC net outflow on {}", channel),
+                description: format!(
+                    "Net outflow of *{}* _{}_ over *{}* within the last {} ({} out, {} in), which is {:.2}% of its {} of {}.",
+                    net_outflow,
+                    state.token_name(&token),
+                    channel,
+                    self.window,
+                    state.to_f64(&token, volume.outbound),
+                    state.to_f64(&token, volume.inbound),
+                    net_outflow / reference * 100.0,
+                    reference_name,
+                    reference
+                ),
+                metadata: Metadata::new(Some(last_state.block.height as u32), None),
+                severity: Severity::High,
+                trigger_after: Some(Duration::from_secs(60 * 60)),
+                continous: self.is_continous(),
+            });
+        }
+
+        alerts
+    }
+
+    fn is_continous(&self) -> bool {
+        false
+    }
+}
+
+impl Display for IbcFlowCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IbcFlowCheck(net_outflow_ratio: {}, window: {})",
+            self.net_outflow_ratio, self.window
+        )
+    }
+}
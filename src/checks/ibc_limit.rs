@@ -1,13 +1,41 @@
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
+
+use crate::checks::AppConfig;
 
 use super::CheckTrait;
 
 const LIMIT_IBC_CHECK_ID: &str = "ibc_limit_check";
+const THROUGHPUT_IBC_CHECK_ID: &str = "ibc_throughput_check";
 
-const LIMIT_THRESHOLD: f64 = 0.80;
+#[derive(Debug, Clone, Copy)]
+struct IbcLimitThreshold {
+    mint_limit: f64,
+    throughput: f64,
+}
 
-#[derive(Default)]
-pub struct IbcLimitCheck {}
+pub struct IbcLimitCheck {
+    thresholds: HashMap<String, IbcLimitThreshold>,
+}
+
+impl IbcLimitCheck {
+    pub fn new(config: &AppConfig) -> Self {
+        let thresholds = config
+            .get_config()
+            .tokens_ibc_thresholds()
+            .into_iter()
+            .map(|(token, mint_limit, throughput)| {
+                (
+                    token,
+                    IbcLimitThreshold {
+                        mint_limit,
+                        throughput,
+                    },
+                )
+            })
+            .collect();
+        Self { thresholds }
+    }
+}
 
 #[async_trait::async_trait]
 impl CheckTrait for IbcLimitCheck {
@@ -16,16 +44,21 @@ impl CheckTrait for IbcLimitCheck {
         let mut alerts = Vec::new();
 
         for supply in &last_state.supplies {
-            let limit = last_state.mint_limit.get(&supply.token);
-            if let Some(limit) = limit {
-                if *limit > 0 && supply.total as f64 > *limit as f64 * LIMIT_THRESHOLD {
+            let limit = last_state.ibc_limits.get(&supply.token);
+            let threshold = self.thresholds.get(&supply.token);
+            if let (Some(limit), Some(threshold)) = (limit, threshold) {
+                let total = state.to_f64(&supply.token, supply.total);
+                let limit = state.to_f64(&supply.token, limit.mint_limit);
+                if limit > 0.0 && total > limit * threshold.mint_limit {
                     let alert = crate::shared::alert::Alert {
                         check_id: format!("{}_{}", LIMIT_IBC_CHECK_ID, supply.token),
                         severity: crate::shared::alert::Severity::Low,
                         title: "IBC Token Supply Limit Alert".to_string(),
                         description: format!(
                             "IBC token {} supply {} almost reached limit {}",
-                            supply.token, supply.total, limit
+                            state.token_name(&supply.token),
+                            total,
+                            limit
                         ),
                         metadata: crate::shared::alert::Metadata {
                             block_height: Some(last_state.block.height as u32),
@@ -39,6 +72,41 @@ impl CheckTrait for IbcLimitCheck {
             }
         }
 
+        for (token, limit) in &last_state.ibc_limits {
+            let (Some(usage), Some(threshold)) =
+                (limit.throughput_usage(), self.thresholds.get(token))
+            else {
+                continue;
+            };
+            if usage <= threshold.throughput {
+                continue;
+            }
+
+            let alert = crate::shared::alert::Alert {
+                check_id: format!(
+                    "{}_{}_{}",
+                    THROUGHPUT_IBC_CHECK_ID, token, last_state.block.epoch
+                ),
+                severity: crate::shared::alert::Severity::Medium,
+                title: "IBC Throughput Limit Alert".to_string(),
+                description: format!(
+                    "IBC throughput of token {} is {} in epoch {}, {:.2}% of the per-epoch limit {}. Transfers exceeding the limit will be rejected until the next epoch.",
+                    state.token_name(token),
+                    state.to_f64(token, limit.throughput),
+                    last_state.block.epoch,
+                    usage * 100.0,
+                    state.to_f64(token, limit.throughput_limit)
+                ),
+                metadata: crate::shared::alert::Metadata {
+                    block_height: Some(last_state.block.height as u32),
+                    tx_id: None,
+                },
+                continous: self.is_continous(),
+                trigger_after: Some(std::time::Duration::from_secs(60 * 60)),
+            };
+            alerts.push(alert);
+        }
+
         alerts
     }
 
@@ -49,6 +117,6 @@ impl CheckTrait for IbcLimitCheck {
 
 impl Display for IbcLimitCheck {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "IbcLimitCheck")
+        write!(f, "IbcLimitCheck with {} thresholds", self.thresholds.len())
     }
 }
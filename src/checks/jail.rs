@@ -0,0 +1,82 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::shared::alert::{Alert, Metadata, Severity};
+
+use super::{AppConfig, CheckTrait};
+
+const JAIL_CHECK_ID: &str = "jail_check";
+
+pub struct JailCheck {
+    validators: Vec<(String, String)>,
+    jail_warning_ratio: f64,
+}
+
+impl JailCheck {
+    pub fn new(config: &AppConfig) -> Self {
+        let config = config.get_config();
+        Self {
+            validators: config.validators(),
+            jail_warning_ratio: config.pos.jail_warning_ratio,
+        }
+    }
+
+    fn alias(&self, address: &str) -> Option<&str> {
+        self.validators
+            .iter()
+            .find(|(_, validator)| validator == address)
+            .map(|(alias, _)| alias.as_str())
+    }
+}
+
+#[async_trait::async_trait]
+impl CheckTrait for JailCheck {
+    async fn check(&self, state: &super::State) -> Vec<Alert> {
+        let last_state = state.last_block();
+        let liveness = &last_state.liveness;
+
+        last_state
+            .consensus_validators()
+            .into_iter()
+            .filter_map(|validator| {
+                let missed_votes =
+                    liveness.nearing_jail(&validator.address, self.jail_warning_ratio)?;
+
+                let (name, severity) = match self.alias(&validator.address) {
+                    Some(alias) => (format!("{} ({})", alias, validator.address), Severity::High),
+                    None => (validator.address.clone(), Severity::Medium),
+                };
+
+                Some(Alert {
+                    check_id: format!("{}_{}", JAIL_CHECK_ID, validator.address),
+                    title: "Validator close to being jailed".to_string(),
+                    description: format!(
+                        "Validator *{}* missed *{}* votes over the last {} blocks, it will be jailed at *{}* missed votes ({:.2}% of the threshold)",
+                        name,
+                        missed_votes,
+                        liveness.window,
+                        liveness.missed_votes_threshold,
+                        missed_votes as f64 / liveness.missed_votes_threshold as f64 * 100.0
+                    ),
+                    metadata: Metadata::new(Some(last_state.block.height as u32), None),
+                    severity,
+                    trigger_after: Some(Duration::from_secs(60 * 60)),
+                    continous: self.is_continous(),
+                })
+            })
+            .collect()
+    }
+
+    fn is_continous(&self) -> bool {
+        false
+    }
+}
+
+impl Display for JailCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "JailCheck(jail_warning_ratio: {})",
+            self.jail_warning_ratio
+        )
+    }
+}
@@ -1,13 +1,27 @@
+mod anomaly;
 mod block;
+mod decode;
 mod fees;
 mod gas;
+mod governance;
+mod governance_vote;
 mod halt;
 mod ibc;
+mod ibc_flow;
 mod ibc_limit;
+mod jail;
+mod parameters;
 mod pos;
 mod slashes;
+mod supply;
 mod transfer_limit;
 mod tx;
+mod upgrade;
+mod uptime;
+mod validator_metadata;
+mod validator_set;
+mod velocity;
+mod watch;
 
 use std::fmt::Display;
 
@@ -17,9 +31,13 @@ pub use crate::config::AppConfig;
 pub use crate::state::State;
 use crate::{
     checks::{
-        block::BlockCheck, fees::FeeCheck, gas::GasCheck, halt::HaltCheck, ibc::IbcCheck,
-        ibc_limit::IbcLimitCheck, pos::PoSCheck, slashes::SlashCheck,
-        transfer_limit::TransferLimitCheck, tx::TxCheck,
+        anomaly::AnomalyCheck, block::BlockCheck, decode::DecodeCheck, fees::FeeCheck,
+        gas::GasCheck, governance::GovernanceCheck, governance_vote::GovernanceVoteCheck,
+        halt::HaltCheck, ibc::IbcCheck, ibc_flow::IbcFlowCheck, ibc_limit::IbcLimitCheck,
+        jail::JailCheck, parameters::ParametersCheck, pos::PoSCheck, slashes::SlashCheck,
+        supply::SupplyCheck, transfer_limit::TransferLimitCheck, tx::TxCheck,
+        upgrade::UpgradeCheck, uptime::UptimeCheck, validator_metadata::ValidatorMetadataCheck,
+        validator_set::ValidatorSetCheck, velocity::VelocityCheck, watch::WatchCheck,
     },
     shared::alert::Alert,
 };
@@ -45,8 +63,22 @@ impl CheckManager {
             Box::new(GasCheck::new(config)),
             Box::new(IbcCheck::new(config)),
             Box::new(TransferLimitCheck::new(config)),
-            Box::new(SlashCheck::default()),
-            Box::new(IbcLimitCheck::default()),
+            Box::new(SlashCheck::new(config)),
+            Box::new(IbcLimitCheck::new(config)),
+            Box::new(DecodeCheck::default()),
+            Box::new(UptimeCheck::new(config)),
+            Box::new(JailCheck::new(config)),
+            Box::new(ValidatorSetCheck::new(config)),
+            Box::new(ValidatorMetadataCheck::new(config)),
+            Box::new(WatchCheck::new(config)),
+            Box::new(VelocityCheck::new(config)),
+            Box::new(AnomalyCheck::new(config)),
+            Box::new(IbcFlowCheck::new(config)),
+            Box::new(GovernanceCheck::default()),
+            Box::new(GovernanceVoteCheck::new(config)),
+            Box::new(UpgradeCheck::default()),
+            Box::new(ParametersCheck::default()),
+            Box::new(SupplyCheck::new(config)),
         ];
         Self { checks }
     }
@@ -0,0 +1,63 @@
+use std::fmt::Display;
+
+use crate::shared::alert::{Alert, Metadata, Severity};
+
+use super::CheckTrait;
+
+const PARAMETERS_CHECK_ID: &str = "parameters_check";
+
+#[derive(Default)]
+pub struct ParametersCheck {}
+
+#[async_trait::async_trait]
+impl CheckTrait for ParametersCheck {
+    async fn check(&self, state: &super::State) -> Vec<Alert> {
+        let last_state = state.last_block();
+        let prev_state = state.prev_block();
+        if prev_state.parameters.is_empty() {
+            return vec![];
+        }
+
+        let changes = last_state.parameters.changes(&prev_state.parameters);
+        if changes.is_empty() {
+            return vec![];
+        }
+
+        let description = changes
+            .iter()
+            .map(|change| {
+                format!(
+                    "- *{}*: {} → {}",
+                    change.name,
+                    change.before.as_deref().unwrap_or("none"),
+                    change.after.as_deref().unwrap_or("none")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        vec![Alert {
+            check_id: format!("{}_{}", PARAMETERS_CHECK_ID, last_state.block.epoch),
+            title: format!(
+                "{} protocol parameter(s) changed in epoch {}",
+                changes.len(),
+                last_state.block.epoch
+            ),
+            description,
+            metadata: Metadata::new(Some(last_state.block.height as u32), None),
+            severity: Severity::High,
+            trigger_after: None,
+            continous: self.is_continous(),
+        }]
+    }
+
+    fn is_continous(&self) -> bool {
+        false
+    }
+}
+
+impl Display for ParametersCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ParametersCheck")
+    }
+}
@@ -1,5 +1,7 @@
 use std::{fmt::Display, time::Duration};
 
+use crate::shared::token;
+
 use super::{AppConfig, CheckTrait};
 
 const POS_ONE_THIRD_CHECK_ID: &str = "pos_one_third_check";
@@ -103,8 +105,8 @@ impl CheckTrait for PoSCheck {
             });
         }
 
-        let current_bonds = last_state.bonds as f64;
-        let prev_bonds = prev_state.bonds as f64;
+        let current_bonds = token::native_to_f64(last_state.bonds);
+        let prev_bonds = token::native_to_f64(prev_state.bonds);
         if prev_bonds + prev_bonds * self.bond_increase_threshold < current_bonds {
             alerts.push(crate::shared::alert::Alert {
                 check_id: POS_BONDS_CHECK_ID.to_string(),
@@ -123,8 +125,8 @@ impl CheckTrait for PoSCheck {
             });
         }
 
-        let current_unbonds = last_state.unbonds as f64;
-        let prev_unbonds = prev_state.unbonds as f64;
+        let current_unbonds = token::native_to_f64(last_state.unbonds);
+        let prev_unbonds = token::native_to_f64(prev_state.unbonds);
         if prev_unbonds + prev_unbonds * self.unbond_increase_threshold < current_unbonds {
             alerts.push(crate::shared::alert::Alert {
                 check_id: POS_UNBONDS_CHECK_ID.to_string(),
@@ -1,36 +1,146 @@
 use std::fmt::Display;
 
-use namada_sdk::tendermint::evidence;
+use namada_sdk::{tendermint::evidence, token::Amount};
 
 use crate::{
-    shared::alert::{Alert, Metadata, Severity},
-    state::State,
+    shared::{
+        alert::{Alert, Metadata, Severity},
+        slashes::SlashRecord,
+        token,
+    },
+    state::{BlockState, State},
 };
 
-use super::CheckTrait;
+use super::{AppConfig, CheckTrait};
 
 const SLASHES_CHECK_ID: &str = "slash_check";
 
-#[derive(Debug, Clone, Default)]
-pub struct SlashCheck {}
+#[derive(Debug, Clone)]
+pub struct SlashCheck {
+    validators: Vec<(String, String)>,
+}
+
+impl SlashCheck {
+    pub fn new(config: &AppConfig) -> Self {
+        let config = config.get_config();
+        Self {
+            validators: config.validators(),
+        }
+    }
+
+    fn alias(&self, address: &str) -> Option<&str> {
+        self.validators
+            .iter()
+            .find(|(_, validator)| validator == address)
+            .map(|(alias, _)| alias.as_str())
+    }
+
+    /// Severity scales with the share of the voting power slashed. Our own validators being
+    /// slashed is always critical.
+    fn severity(&self, slash: &SlashRecord, total_voting_power: Amount) -> Severity {
+        if self.alias(&slash.validator).is_some() {
+            return Severity::Critical;
+        }
+        let share = voting_power_share(slash, total_voting_power);
+        if share >= 1.0 / 3.0 {
+            Severity::Critical
+        } else if share >= 0.1 {
+            Severity::High
+        } else if share >= 0.01 {
+            Severity::Medium
+        } else {
+            Severity::Low
+        }
+    }
+
+    /// Describe the PoS slashes enqueued for the offenders of an evidence and pick the highest
+    /// severity among them
+    fn describe_slashes(
+        &self,
+        last_state: &BlockState,
+        total_voting_power: Amount,
+        consensus_addresses: &[String],
+    ) -> (String, Severity) {
+        let offenders = consensus_addresses
+            .iter()
+            .filter_map(|consensus_address| {
+                last_state.validator_by_consensus_address(consensus_address)
+            })
+            .map(|validator| validator.address.as_str())
+            .collect::<Vec<_>>();
+
+        // Evidence against our own validators is critical, whether or not PoS has enqueued a
+        // slash for it yet
+        let mut severity = if offenders
+            .iter()
+            .any(|offender| self.alias(offender).is_some())
+        {
+            Severity::Critical
+        } else {
+            Severity::Low
+        };
+        let mut lines = vec![];
+        for slash in last_state
+            .slashes
+            .iter()
+            .filter(|slash| offenders.contains(&slash.validator.as_str()))
+        {
+            let slash_severity = self.severity(slash, total_voting_power);
+            if slash_severity > severity {
+                severity = slash_severity;
+            }
+            let name = match self.alias(&slash.validator) {
+                Some(alias) => format!("{} ({})", alias, slash.validator),
+                None => slash.validator.clone(),
+            };
+            lines.push(format!(
+                "- *{}*: {} at height {} (epoch {}), slash rate of at least {:.2}%, takes effect in epoch {}, stake affected {} ({:.2}% of the voting power)",
+                name,
+                slash.kind,
+                slash.block_height,
+                slash.infraction_epoch,
+                slash.rate * 100.0,
+                slash.processing_epoch,
+                slash.stake.to_string_native(),
+                voting_power_share(slash, total_voting_power) * 100.0
+            ));
+        }
+
+        if lines.is_empty() {
+            return (String::new(), severity);
+        }
+        (format!("\nPoS slashes:\n{}", lines.join("\n")), severity)
+    }
+}
+
+fn voting_power_share(slash: &SlashRecord, total_voting_power: Amount) -> f64 {
+    token::ratio(slash.stake, total_voting_power)
+}
 
 #[async_trait::async_trait]
 impl CheckTrait for SlashCheck {
     async fn check(&self, state: &State) -> Vec<Alert> {
         let last_state = state.last_block();
+        let total_voting_power = state.total_voting_power();
 
         last_state.block.block.evidence.iter().map(|evidence| {
             match evidence {
                 evidence::Evidence::DuplicateVote(duplicate_vote_evidence) => {
+                    let (slashes, severity) = self.describe_slashes(
+                        &last_state,
+                        total_voting_power,
+                        &[duplicate_vote_evidence.vote_a.validator_address.to_string()],
+                    );
                     let description = format!(
-                        "✂️ Duplicate vote evidence found. Total power: {}. Validator {} voted {} at height {}. Validator {} voted {} at height {}.",
+                        "✂️ Duplicate vote evidence found. Total power: {}. Validator {} voted {} at height {}. Validator {} voted {} at height {}.{}",
                         duplicate_vote_evidence.total_voting_power,
-                        duplicate_vote_evidence.vote_a.validator_address,
+                        last_state.validator_name(&duplicate_vote_evidence.vote_a.validator_address.to_string()),
                         duplicate_vote_evidence.vote_a.vote_type,
                         duplicate_vote_evidence.vote_a.height,
-                        duplicate_vote_evidence.vote_b.validator_address,
+                        last_state.validator_name(&duplicate_vote_evidence.vote_b.validator_address.to_string()),
                         duplicate_vote_evidence.vote_b.vote_type,
-                        duplicate_vote_evidence.vote_b.height
+                        duplicate_vote_evidence.vote_b.height,
+                        slashes
                     );
                     let title = format!(
                         "Duplicate vote evidence found for block {}",
@@ -43,28 +153,45 @@ impl CheckTrait for SlashCheck {
                             Some(last_state.block.block.header.height.value() as u32),
                             None
                         ),
-                        severity: Severity::Low,
+                        severity,
                         check_id: SLASHES_CHECK_ID.to_string(),
                         trigger_after: None,
                         continous: self.is_continous(),
                     }
                 }
                 evidence::Evidence::LightClientAttack(light_client_attack_evidence) => {
+                    let byzantine_validators = light_client_attack_evidence
+                        .byzantine_validators
+                        .iter()
+                        .map(|validator| validator.address.to_string())
+                        .collect::<Vec<_>>();
+                    let (slashes, severity) = self.describe_slashes(
+                        &last_state,
+                        total_voting_power,
+                        &byzantine_validators,
+                    );
                     let description = format!(
-                        "✂️ Light client attack evidence found. Total power: {}. Conflicting block height: {}. Conflicting block proposer: {}. Common height: {}. Byzantine validators: {:?}.",
+                        "✂️ Light client attack evidence found. Total power: {}. Conflicting block height: {}. Conflicting block proposer: {}. Common height: {}. Byzantine validators: {:?}.{}",
                         light_client_attack_evidence.total_voting_power,
                         light_client_attack_evidence
                             .conflicting_block
                             .signed_header
                             .header
                             .height,
-                        light_client_attack_evidence
-                            .conflicting_block
-                            .signed_header
-                            .header
-                            .proposer_address,
+                        last_state.validator_name(
+                            &light_client_attack_evidence
+                                .conflicting_block
+                                .signed_header
+                                .header
+                                .proposer_address
+                                .to_string()
+                        ),
                         light_client_attack_evidence.common_height,
-                        light_client_attack_evidence.byzantine_validators
+                        byzantine_validators
+                            .iter()
+                            .map(|validator| last_state.validator_name(validator))
+                            .collect::<Vec<_>>(),
+                        slashes
                     );
                     let title = format!(
                         "Light client attack evidence found for block {}",
@@ -77,7 +204,7 @@ impl CheckTrait for SlashCheck {
                             Some(last_state.block.block.header.height.value() as u32),
                             None
                         ),
-                        severity: Severity::Low,
+                        severity,
                         check_id: SLASHES_CHECK_ID.to_string(),
                         trigger_after: None,
                         continous: self.is_continous()
@@ -0,0 +1,80 @@
+use std::fmt::Display;
+
+use crate::shared::alert::{Alert, Metadata, Severity};
+
+use super::{AppConfig, CheckTrait};
+
+const SUPPLY_CHECK_ID: &str = "supply_check";
+
+pub struct SupplyCheck {
+    pgf_tolerance: f64,
+}
+
+impl SupplyCheck {
+    pub fn new(config: &AppConfig) -> Self {
+        Self {
+            pgf_tolerance: config.get_config().supply.pgf_tolerance,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CheckTrait for SupplyCheck {
+    async fn check(&self, state: &super::State) -> Vec<Alert> {
+        let last_state = state.last_block();
+        let height = last_state.block.height;
+        let mut alerts = vec![];
+
+        for audit in &last_state.supply_audits {
+            let Some((unexplained, above)) = audit.unexplained_beyond(self.pgf_tolerance) else {
+                continue;
+            };
+            let token = &audit.token;
+            let unexplained = state.to_f64(token, unexplained);
+
+            let inflation = audit
+                .inflation
+                .map(|inflation| {
+                    format!(
+                        ", PoS inflation {}, MASP rewards {}, PGF inflation ~{}",
+                        state.to_f64(token, inflation.pos),
+                        state.to_f64(token, inflation.masp),
+                        state.to_f64(token, inflation.pgf)
+                    )
+                })
+                .unwrap_or_default();
+            alerts.push(Alert {
+                check_id: format!("{}_{}_{}", SUPPLY_CHECK_ID, token, height),
+                title: format!("Unexplained supply change of {}", state.token_name(token)),
+                description: format!(
+                    "The total supply of _{}_ went from *{}* to *{}*, but the chain records account for *{}* (minted {}, burned {}{}). *{}* were {} without explanation.",
+                    state.token_name(token),
+                    state.to_f64(token, audit.before),
+                    state.to_f64(token, audit.after),
+                    state.to_f64(token, audit.expected()),
+                    state.to_f64(token, audit.minted),
+                    state.to_f64(token, audit.burned),
+                    inflation,
+                    unexplained,
+                    if above { "minted" } else { "burned" }
+                ),
+                metadata: Metadata::new(Some(height as u32), None),
+                severity: Severity::Critical,
+                trigger_after: None,
+                continous: self.is_continous(),
+            });
+        }
+
+        alerts
+    }
+
+    fn is_continous(&self) -> bool {
+        false
+    }
+}
+
+impl Display for SupplyCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SupplyCheck")
+    }
+}
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, fmt::Display};
 
-use namada_sdk::{ibc, token::Amount};
+use namada_sdk::ibc;
 
 use crate::checks::AppConfig;
 
@@ -10,8 +10,7 @@ const FLOW_IBC_CHECK_ID: &str = "transfer_limit_check";
 
 #[derive(Debug, Clone)]
 struct DefaultFeeThreshold {
-    name: String,
-    value: u64,
+    value: f64,
 }
 
 #[derive(Default)]
@@ -22,14 +21,8 @@ pub struct TransferLimitCheck {
 impl TransferLimitCheck {
     fn populate_thresholds(&mut self, config: &AppConfig) {
         for (address, threshold) in config.get_config().tokens_thresholds() {
-            let token = address.clone();
-            self.thresholds.insert(
-                token.clone(),
-                DefaultFeeThreshold {
-                    name: token,
-                    value: threshold,
-                },
-            );
+            self.thresholds
+                .insert(address, DefaultFeeThreshold { value: threshold });
         }
     }
 
@@ -63,12 +56,22 @@ impl CheckTrait for TransferLimitCheck {
                         let sources = transfer.sources;
                         for (account, amount) in sources {
                             let token = account.token.to_string();
-                            let (token_name, token_threshold) =
-                                if let Some(threshold) = self.thresholds.get(&token) {
-                                    (threshold.name.clone(), Amount::from_u64(threshold.value))
-                                } else {
+                            let (token_name, token_threshold) = match (
+                                self.thresholds.get(&token),
+                                state.token(&token),
+                            ) {
+                                (Some(threshold), Some(denominated)) => {
+                                    (denominated.name(), denominated.from_f64(threshold.value))
+                                }
+                                (Some(_), None) => {
+                                    tracing::warn!(
+                                            "Unknown denomination for token {}, can't check transfer threshold",
+                                            token
+                                        );
                                     continue;
-                                };
+                                }
+                                _ => continue,
+                            };
 
                             if amount.amount() <= token_threshold {
                                 continue;
@@ -80,7 +83,7 @@ impl CheckTrait for TransferLimitCheck {
                                 title: "Transfer exceeded threshold".to_string(),
                                 description: format!(
                                     "Source {} made a transfer of {} {}, which exceeds the threshold of {}",
-                                    &account.owner, amount.amount(), token_name, token_threshold
+                                    &account.owner, state.to_f64(&token, amount.amount()), token_name, state.to_f64(&token, token_threshold)
                                 ),
                                 metadata: crate::shared::alert::Metadata {
                                     block_height: Some(last_state.block.height as u32),
@@ -106,12 +109,22 @@ impl CheckTrait for TransferLimitCheck {
 
                         for (account, amount) in sources {
                             let token = account.token.to_string();
-                            let (token_name, token_threshold) =
-                                if let Some(threshold) = self.thresholds.get(&token) {
-                                    (threshold.name.clone(), Amount::from_u64(threshold.value))
-                                } else {
+                            let (token_name, token_threshold) = match (
+                                self.thresholds.get(&token),
+                                state.token(&token),
+                            ) {
+                                (Some(threshold), Some(denominated)) => {
+                                    (denominated.name(), denominated.from_f64(threshold.value))
+                                }
+                                (Some(_), None) => {
+                                    tracing::warn!(
+                                            "Unknown denomination for token {}, can't check transfer threshold",
+                                            token
+                                        );
                                     continue;
-                                };
+                                }
+                                _ => continue,
+                            };
 
                             if amount.amount() <= token_threshold {
                                 continue;
@@ -123,7 +136,7 @@ impl CheckTrait for TransferLimitCheck {
                                 title: "IBC Transfer exceeded threshold".to_string(),
                                 description: format!(
                                     "Source {} made an IBC transfer of {} {}, which exceeds the threshold of {}",
-                                    &account.owner, amount.amount(), token_name, token_threshold
+                                    &account.owner, state.to_f64(&token, amount.amount()), token_name, state.to_f64(&token, token_threshold)
                                 ),
                                 metadata: crate::shared::alert::Metadata {
                                     block_height: Some(last_state.block.height as u32),
@@ -0,0 +1,81 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::shared::{
+    alert::{Alert, Metadata, Severity},
+    checksums::Checksums,
+    namada::InnerKind,
+};
+
+use super::CheckTrait;
+
+const UPGRADE_CHECK_ID: &str = "upgrade_check";
+const UNKNOWN_CODE_CHECK_ID: &str = "unknown_code_check";
+
+#[derive(Default)]
+pub struct UpgradeCheck {}
+
+#[async_trait::async_trait]
+impl CheckTrait for UpgradeCheck {
+    async fn check(&self, state: &super::State) -> Vec<Alert> {
+        let last_state = state.last_block();
+        let prev_state = state.prev_block();
+        let height = last_state.block.height;
+
+        let mut alerts = last_state
+            .checksums
+            .changes(&prev_state.checksums)
+            .into_iter()
+            .map(|change| Alert {
+                check_id: format!("{}_{}", UPGRADE_CHECK_ID, change.name),
+                title: format!("Transaction code {} changed", change.name),
+                description: format!(
+                    "The code hash of *{}* changed from *{}* to *{}* at block {}. A protocol upgrade may have happened",
+                    change.name,
+                    change.before.as_deref().unwrap_or("none"),
+                    change.after.as_deref().unwrap_or("none"),
+                    height
+                ),
+                metadata: Metadata::new(Some(height as u32), None),
+                severity: Severity::High,
+                trigger_after: None,
+                continous: self.is_continous(),
+            })
+            .collect::<Vec<_>>();
+
+        for wrapper in &last_state.block.transactions {
+            for inner in &wrapper.inners {
+                let InnerKind::Unknown(code_name, _) = &inner.kind else {
+                    continue;
+                };
+                let Some(code_id) = Checksums::unknown_code_id(code_name) else {
+                    continue;
+                };
+
+                alerts.push(Alert {
+                    check_id: format!("{}_{}", UNKNOWN_CODE_CHECK_ID, code_id),
+                    title: "Transaction with unknown code".to_string(),
+                    description: format!(
+                        "Inner transaction *{}* ran code *{}*, which matches no known transaction code",
+                        inner.id, code_id
+                    ),
+                    metadata: Metadata::new(Some(height as u32), Some(inner.id.clone())),
+                    severity: Severity::High,
+                    trigger_after: Some(Duration::from_secs(60 * 60)),
+                    continous: self.is_continous(),
+                });
+            }
+        }
+
+        alerts
+    }
+
+    fn is_continous(&self) -> bool {
+        false
+    }
+}
+
+impl Display for UpgradeCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UpgradeCheck")
+    }
+}
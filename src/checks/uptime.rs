@@ -0,0 +1,80 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::shared::alert::{Alert, Metadata, Severity};
+
+use super::{AppConfig, CheckTrait};
+
+const UPTIME_CHECK_ID: &str = "uptime_check";
+
+pub struct UptimeCheck {
+    validators: Vec<(String, String)>,
+    missed_blocks_threshold: u64,
+    missed_blocks_window: u64,
+}
+
+impl UptimeCheck {
+    pub fn new(config: &AppConfig) -> Self {
+        let config = config.get_config();
+        Self {
+            validators: config.validators(),
+            missed_blocks_threshold: config.pos.missed_blocks_threshold,
+            missed_blocks_window: config.pos.missed_blocks_window,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CheckTrait for UptimeCheck {
+    async fn check(&self, state: &super::State) -> Vec<Alert> {
+        if self.validators.is_empty() {
+            return vec![];
+        }
+
+        let last_state = state.last_block();
+        let stats = state.signing_stats(self.missed_blocks_window as usize);
+
+        self.validators
+            .iter()
+            .filter_map(|(alias, address)| {
+                let stats = stats.get(address)?;
+                if stats.missed < self.missed_blocks_threshold {
+                    return None;
+                }
+
+                Some(Alert {
+                    check_id: format!("{}_{}", UPTIME_CHECK_ID, address),
+                    title: format!("Validator {} is missing blocks", alias),
+                    description: format!(
+                        "Validator *{}* ({}) missed *{}* of the last *{}* blocks (uptime {:.2}%), threshold is {} missed blocks",
+                        alias,
+                        address,
+                        stats.missed,
+                        stats.total(),
+                        stats.uptime() * 100.0,
+                        self.missed_blocks_threshold
+                    ),
+                    metadata: Metadata::new(Some(last_state.block.height as u32), None),
+                    severity: Severity::High,
+                    trigger_after: Some(Duration::from_secs(60 * 30)),
+                    continous: self.is_continous(),
+                })
+            })
+            .collect()
+    }
+
+    fn is_continous(&self) -> bool {
+        false
+    }
+}
+
+impl Display for UptimeCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "UptimeCheck({} validators, missed_blocks_threshold: {}, missed_blocks_window: {})",
+            self.validators.len(),
+            self.missed_blocks_threshold,
+            self.missed_blocks_window
+        )
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt::Display;
+
+use crate::shared::alert::{Alert, Metadata, Severity};
+
+use super::{AppConfig, CheckTrait};
+
+const VALIDATOR_METADATA_CHECK_ID: &str = "validator_metadata_check";
+
+pub struct ValidatorMetadataCheck {
+    validators: Vec<(String, String)>,
+    commission_increase_threshold: f64,
+}
+
+impl ValidatorMetadataCheck {
+    pub fn new(config: &AppConfig) -> Self {
+        let config = config.get_config();
+        Self {
+            validators: config.validators(),
+            commission_increase_threshold: config.pos.commission_increase_threshold,
+        }
+    }
+
+    fn alias(&self, address: &str) -> Option<&str> {
+        self.validators
+            .iter()
+            .find(|(_, validator)| validator == address)
+            .map(|(alias, _)| alias.as_str())
+    }
+}
+
+fn percent(rate: Option<f64>) -> String {
+    rate.map(|rate| format!("{:.2}%", rate * 100.0))
+        .unwrap_or_else(|| "none".to_string())
+}
+
+#[async_trait::async_trait]
+impl CheckTrait for ValidatorMetadataCheck {
+    async fn check(&self, state: &super::State) -> Vec<Alert> {
+        let last_state = state.last_block();
+        let mut alerts = vec![];
+
+        for change in &last_state.metadata_changes {
+            let name = match self.alias(&change.address) {
+                Some(alias) => format!("{} ({})", alias, change.address),
+                None => change.address.clone(),
+            };
+
+            if let Some(increase) = change.commission_increase() {
+                if increase > self.commission_increase_threshold {
+                    alerts.push(Alert {
+                        check_id: format!(
+                            "{}_commission_{}_{}",
+                            VALIDATOR_METADATA_CHECK_ID, change.address, change.tx_id
+                        ),
+                        title: "Validator commission increase".to_string(),
+                        description: format!(
+                            "Validator *{}* raised its commission rate from *{}* to *{}*, effective from the pipeline epoch. The increase is above the threshold of *{}*.",
+                            name,
+                            percent(change.before.commission_rate),
+                            percent(change.after.commission_rate),
+                            percent(Some(self.commission_increase_threshold))
+                        ),
+                        metadata: Metadata::new(
+                            Some(last_state.block.height as u32),
+                            Some(change.tx_id.clone()),
+                        ),
+                        severity: Severity::High,
+                        trigger_after: None,
+                        continous: self.is_continous(),
+                    });
+                }
+            }
+
+            if self.alias(&change.address).is_none() {
+                continue;
+            }
+
+            let field_changes = change.field_changes();
+            if field_changes.is_empty() {
+                continue;
+            }
+
+            let description = field_changes
+                .iter()
+                .map(|field_change| {
+                    format!(
+                        "- *{}*: {} → {}",
+                        field_change.field,
+                        field_change.before.as_deref().unwrap_or("none"),
+                        field_change.after.as_deref().unwrap_or("none")
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            alerts.push(Alert {
+                check_id: format!(
+                    "{}_metadata_{}_{}",
+                    VALIDATOR_METADATA_CHECK_ID, change.address, change.tx_id
+                ),
+                title: "Validator metadata change".to_string(),
+                description: format!(
+                    "Validator *{}* changed its metadata:\n{}",
+                    name, description
+                ),
+                metadata: Metadata::new(
+                    Some(last_state.block.height as u32),
+                    Some(change.tx_id.clone()),
+                ),
+                severity: Severity::Medium,
+                trigger_after: None,
+                continous: self.is_continous(),
+            });
+        }
+
+        alerts
+    }
+
+    fn is_continous(&self) -> bool {
+        false
+    }
+}
+
+impl Display for ValidatorMetadataCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ValidatorMetadataCheck(commission_increase_threshold: {})",
+            self.commission_increase_threshold
+        )
+    }
+}
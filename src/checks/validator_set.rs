@@ -0,0 +1,100 @@
+use std::fmt::Display;
+
+use crate::shared::{
+    alert::{Alert, Metadata, Severity},
+    namada::ValidatorSetChangeKind,
+};
+
+use super::{AppConfig, CheckTrait};
+
+const VALIDATOR_SET_CHECK_ID: &str = "validator_set_check";
+
+pub struct ValidatorSetCheck {
+    validators: Vec<(String, String)>,
+    voting_power_shift_threshold: f64,
+}
+
+impl ValidatorSetCheck {
+    pub fn new(config: &AppConfig) -> Self {
+        let config = config.get_config();
+        Self {
+            validators: config.validators(),
+            voting_power_shift_threshold: config.pos.voting_power_shift_threshold,
+        }
+    }
+
+    fn alias(&self, address: &str) -> Option<&str> {
+        self.validators
+            .iter()
+            .find(|(_, validator)| validator == address)
+            .map(|(alias, _)| alias.as_str())
+    }
+}
+
+#[async_trait::async_trait]
+impl CheckTrait for ValidatorSetCheck {
+    async fn check(&self, state: &super::State) -> Vec<Alert> {
+        let last_state = state.last_block();
+
+        state
+            .validator_set_changes()
+            .into_iter()
+            .filter_map(|change| {
+                let severity = match &change.kind {
+                    ValidatorSetChangeKind::Jailed => Severity::High,
+                    ValidatorSetChangeKind::LeftConsensus
+                    | ValidatorSetChangeKind::BecameInactive => Severity::Medium,
+                    ValidatorSetChangeKind::VotingPowerChanged { .. } => {
+                        let shift = change.voting_power_shift().unwrap_or_default();
+                        if shift.abs() < self.voting_power_shift_threshold {
+                            return None;
+                        }
+                        Severity::Low
+                    }
+                    _ => Severity::Low,
+                };
+
+                // Adverse changes to our own validators are escalated
+                let (name, severity) = match self.alias(&change.address) {
+                    Some(alias) if severity > Severity::Low => {
+                        (format!("{} ({})", alias, change.address), Severity::High)
+                    }
+                    Some(alias) => (format!("{} ({})", alias, change.address), severity),
+                    None => (change.address.clone(), severity),
+                };
+
+                Some(Alert {
+                    check_id: format!(
+                        "{}_{}_{}",
+                        VALIDATOR_SET_CHECK_ID,
+                        change.kind.label(),
+                        change.address
+                    ),
+                    title: format!("Validator set change: {}", change.kind.label()),
+                    description: format!(
+                        "Validator *{}*: {} in epoch {}",
+                        name, change.kind, last_state.block.epoch
+                    ),
+                    metadata: Metadata::new(Some(last_state.block.height as u32), None),
+                    severity,
+                    trigger_after: None,
+                    continous: self.is_continous(),
+                })
+            })
+            .collect()
+    }
+
+    fn is_continous(&self) -> bool {
+        false
+    }
+}
+
+impl Display for ValidatorSetCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ValidatorSetCheck(voting_power_shift_threshold: {})",
+            self.voting_power_shift_threshold
+        )
+    }
+}
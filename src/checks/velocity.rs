@@ -0,0 +1,174 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use namada_sdk::token::Amount;
+
+use crate::{
+    shared::alert::{Alert, Metadata, Severity},
+    state::{Window, MAX_BLOCKS},
+};
+
+use super::{AppConfig, CheckTrait};
+
+const VELOCITY_CHECK_ID: &str = "velocity_check";
+const DEFAULT_WINDOW_BLOCKS: u64 = 1000;
+
+struct VelocityLimit {
+    token: String,
+    limit: f64,
+    window: Window,
+    /// Whether we already warned that the window is longer than the blocks kept in the state
+    truncation_warned: AtomicBool,
+}
+
+pub struct VelocityCheck {
+    limits: Vec<VelocityLimit>,
+}
+
+impl VelocityCheck {
+    pub fn new(config: &AppConfig) -> Self {
+        let limits = config
+            .get_config()
+            .velocities
+            .iter()
+            .map(|velocity| {
+                let window = match (velocity.window_seconds, velocity.window_blocks) {
+                    (Some(seconds), _) => Window::Seconds(seconds),
+                    (None, Some(blocks)) => Window::Blocks(blocks),
+                    (None, None) => Window::Blocks(DEFAULT_WINDOW_BLOCKS),
+                };
+                let truncated =
+                    matches!(window, Window::Blocks(blocks) if blocks as usize > MAX_BLOCKS);
+                if truncated {
+                    tracing::warn!(
+                        "Velocity window of {} for token {} is longer than the {} blocks kept in memory and will be capped",
+                        window,
+                        velocity.token,
+                        MAX_BLOCKS
+                    );
+                }
+                VelocityLimit {
+                    token: velocity.token.clone(),
+                    limit: velocity.limit,
+                    window,
+                    truncation_warned: AtomicBool::new(truncated),
+                }
+            })
+            .collect();
+        Self { limits }
+    }
+}
+
+#[async_trait::async_trait]
+impl CheckTrait for VelocityCheck {
+    async fn check(&self, state: &super::State) -> Vec<Alert> {
+        let last_state = state.last_block();
+        let mut alerts = vec![];
+
+        // Only sources that sent funds in the last block can have crossed a limit
+        let sources = last_state
+            .outflows()
+            .into_iter()
+            .map(|(source, token, _)| (source, token))
+            .filter(|(_, token)| self.limits.iter().any(|limit| &limit.token == token))
+            .collect::<HashSet<_>>();
+        if sources.is_empty() {
+            return alerts;
+        }
+
+        // Index of the first block within the window of each limit
+        let starts = self
+            .limits
+            .iter()
+            .map(|limit| state.total_blocks() - state.blocks_within(limit.window).len())
+            .collect::<Vec<_>>();
+        for (limit, start) in self.limits.iter().zip(&starts) {
+            if *start == 0
+                && state.total_blocks() == MAX_BLOCKS
+                && !limit.truncation_warned.swap(true, Ordering::Relaxed)
+            {
+                tracing::warn!(
+                    "Velocity window of {} for token {} is longer than the {} blocks kept in memory and is capped",
+                    limit.window,
+                    limit.token,
+                    MAX_BLOCKS
+                );
+            }
+        }
+
+        // Walk the blocks once, adding each outflow of the watched sources to every limit whose
+        // window contains the block
+        let first = starts.iter().min().copied().unwrap_or_default();
+        let mut outflows: Vec<HashMap<&(String, String), Amount>> =
+            vec![HashMap::new(); self.limits.len()];
+        for (index, block_state) in state.blocks.iter().enumerate().skip(first) {
+            for (source, token, amount) in block_state.outflows() {
+                let Some(key) = sources.get(&(source, token)) else {
+                    continue;
+                };
+                for ((limit, start), totals) in
+                    self.limits.iter().zip(&starts).zip(outflows.iter_mut())
+                {
+                    if index < *start || limit.token != key.1 {
+                        continue;
+                    }
+                    let total = totals.entry(key).or_default();
+                    *total = total.checked_add(amount).unwrap_or_else(Amount::max);
+                }
+            }
+        }
+
+        for (limit, totals) in self.limits.iter().zip(outflows) {
+            let Some(token) = state.token(&limit.token) else {
+                tracing::warn!(
+                    "Unknown denomination for token {}, can't check transfer velocity",
+                    limit.token
+                );
+                continue;
+            };
+            let threshold = token.from_f64(limit.limit);
+
+            for ((source, _), outflow) in totals {
+                if outflow <= threshold {
+                    continue;
+                }
+
+                alerts.push(Alert {
+                    check_id: format!(
+                        "{}_{}_{}_{}",
+                        VELOCITY_CHECK_ID, source, limit.token, last_state.block.height
+                    ),
+                    title: "Transfer velocity exceeded".to_string(),
+                    description: format!(
+                        "Source *{}* sent *{}* _{}_ within the last {}, which exceeds the limit of *{}* _{}_.",
+                        source,
+                        state.to_f64(&limit.token, outflow),
+                        token.name(),
+                        limit.window,
+                        limit.limit,
+                        token.name()
+                    ),
+                    metadata: Metadata::new(Some(last_state.block.height as u32), None),
+                    severity: Severity::High,
+                    trigger_after: None,
+                    continous: self.is_continous(),
+                });
+            }
+        }
+
+        alerts
+    }
+
+    fn is_continous(&self) -> bool {
+        false
+    }
+}
+
+impl Display for VelocityCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "VelocityCheck with {} limits", self.limits.len())
+    }
+}
@@ -0,0 +1,201 @@
+use std::fmt::Display;
+
+use namada_sdk::{ibc::IbcMessage, token::Transfer as NamadaTransfer};
+
+use crate::shared::{
+    alert::{Alert, Metadata, Severity},
+    config::{WatchAction, WatchConfig},
+    namada::InnerKind,
+};
+use crate::state::State;
+
+use super::{AppConfig, CheckTrait};
+
+const WATCH_CHECK_ID: &str = "watch_check";
+
+pub struct WatchCheck {
+    watches: Vec<WatchConfig>,
+}
+
+impl WatchCheck {
+    pub fn new(config: &AppConfig) -> Self {
+        let config = config.get_config();
+        Self {
+            watches: config.watches.clone(),
+        }
+    }
+}
+
+/// Transfers in and out of an address in a transparent transfer
+fn transfer_activity(
+    transfer: &NamadaTransfer,
+    address: &str,
+    state: &State,
+) -> Vec<(WatchAction, String)> {
+    let sources = transfer
+        .sources
+        .iter()
+        .filter(|(account, _)| account.owner.to_string() == address)
+        .map(|(account, amount)| {
+            (
+                WatchAction::TransferOut,
+                format!(
+                    "sent {} {}",
+                    amount,
+                    state.token_name(&account.token.to_string())
+                ),
+            )
+        });
+    let targets = transfer
+        .targets
+        .iter()
+        .filter(|(account, _)| account.owner.to_string() == address)
+        .map(|(account, amount)| {
+            (
+                WatchAction::TransferIn,
+                format!(
+                    "received {} {}",
+                    amount,
+                    state.token_name(&account.token.to_string())
+                ),
+            )
+        });
+
+    sources.chain(targets).collect()
+}
+
+/// Actions an inner transaction performed on behalf of, or towards, an address
+fn inner_activity(kind: &InnerKind, address: &str, state: &State) -> Vec<(WatchAction, String)> {
+    match kind {
+        InnerKind::Transfer(transfer) => transfer_activity(transfer, address, state),
+        InnerKind::IbcMsgTransfer(IbcMessage::Transfer(msg_transfer)) => {
+            let mut activity = msg_transfer
+                .transfer
+                .as_ref()
+                .map(|transfer| transfer_activity(transfer, address, state))
+                .unwrap_or_default();
+            let packet_data = &msg_transfer.message.packet_data;
+            if packet_data.sender.to_string() == address {
+                activity.push((
+                    WatchAction::TransferOut,
+                    format!(
+                        "sent {} over IBC to {}",
+                        packet_data.token, packet_data.receiver
+                    ),
+                ));
+            }
+            activity
+        }
+        InnerKind::Bond(bond) => {
+            let source = bond.source.as_ref().unwrap_or(&bond.validator);
+            if source.to_string() == address || bond.validator.to_string() == address {
+                vec![(
+                    WatchAction::Bond,
+                    format!(
+                        "{} bonded {} to {}",
+                        source,
+                        bond.amount.to_string_native(),
+                        bond.validator
+                    ),
+                )]
+            } else {
+                vec![]
+            }
+        }
+        InnerKind::Unbond(unbond) => {
+            let source = unbond.source.as_ref().unwrap_or(&unbond.validator);
+            if source.to_string() == address || unbond.validator.to_string() == address {
+                vec![(
+                    WatchAction::Unbond,
+                    format!(
+                        "{} unbonded {} from {}",
+                        source,
+                        unbond.amount.to_string_native(),
+                        unbond.validator
+                    ),
+                )]
+            } else {
+                vec![]
+            }
+        }
+        InnerKind::Withdraw(withdraw) => {
+            let source = withdraw.source.as_ref().unwrap_or(&withdraw.validator);
+            if source.to_string() == address || withdraw.validator.to_string() == address {
+                vec![(
+                    WatchAction::Withdraw,
+                    format!("{} withdrew from {}", source, withdraw.validator),
+                )]
+            } else {
+                vec![]
+            }
+        }
+        InnerKind::UpdateAccount(update_account) if update_account.addr.to_string() == address => {
+            vec![(
+                WatchAction::UpdateAccount,
+                format!(
+                    "updated its account: {} public key(s), threshold {}, vp code {}",
+                    update_account.public_keys.len(),
+                    update_account
+                        .threshold
+                        .map_or_else(|| "unchanged".to_string(), |t| t.to_string()),
+                    update_account
+                        .vp_code_hash
+                        .map_or_else(|| "unchanged".to_string(), |h| h.to_string())
+                ),
+            )]
+        }
+        _ => vec![],
+    }
+}
+
+#[async_trait::async_trait]
+impl CheckTrait for WatchCheck {
+    async fn check(&self, state: &State) -> Vec<Alert> {
+        let last_state = state.last_block();
+        let height = last_state.block.height;
+        let mut alerts = vec![];
+
+        let alert =
+            |watch: &WatchConfig, action: WatchAction, tx_id: &str, details: String| Alert {
+                check_id: format!("{}_{}_{}_{}", WATCH_CHECK_ID, watch.address, action, tx_id),
+                title: format!("Activity on watched address {}", watch.label),
+                description: format!(
+                    "*{}* ({}) {}: {}",
+                    watch.label, watch.address, action, details
+                ),
+                metadata: Metadata::new(Some(height as u32), Some(tx_id.to_string())),
+                severity: Severity::High,
+                trigger_after: None,
+                continous: self.is_continous(),
+            };
+
+        for watch in &self.watches {
+            for tx in &last_state.block.transactions {
+                if watch.watches(WatchAction::FeePayer) && tx.fee.gas_payer == watch.address {
+                    let details = format!("paid the fees of transaction {}", tx.id);
+                    alerts.push(alert(watch, WatchAction::FeePayer, &tx.id, details));
+                }
+
+                for inner in tx.inners.iter().filter(|inner| inner.was_applied) {
+                    for (action, details) in inner_activity(&inner.kind, &watch.address, state) {
+                        if watch.watches(action) {
+                            alerts.push(alert(watch, action, &inner.id, details));
+                        }
+                    }
+                }
+            }
+        }
+
+        alerts
+    }
+
+    fn is_continous(&self) -> bool {
+        false
+    }
+}
+
+impl Display for WatchCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WatchCheck(watches: {})", self.watches.len())
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{log::LogConfig, shared::config::Config};
+use crate::{
+    log::LogConfig,
+    shared::{config::Config, discovery::Discovered},
+};
 
 #[derive(clap::Parser)]
 pub struct AppConfig {
@@ -28,6 +31,10 @@ pub struct AppConfig {
 
     #[clap(flatten)]
     pub log: LogConfig,
+
+    /// Channels and tokens found on chain, merged into the file config
+    #[clap(skip)]
+    pub discovered: Discovered,
 }
 
 fn file_exists(path: &str) -> Result<String, String> {
@@ -40,7 +47,10 @@ fn file_exists(path: &str) -> Result<String, String> {
 
 impl AppConfig {
     pub fn get_config(&self) -> Config {
-        toml::de::from_str(&std::fs::read_to_string(&self.config_path).unwrap())
-            .expect("Failed to parse config file")
+        let mut config: Config =
+            toml::de::from_str(&std::fs::read_to_string(&self.config_path).unwrap())
+                .expect("Failed to parse config file");
+        config.merge_discovered(&self.discovered);
+        config
     }
 }
@@ -1 +0,0 @@
-pub const MICRO: f64 = 1000000.0;
@@ -9,6 +9,29 @@ pub enum MainError {
     RpcError,
 }
 
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    #[error("Missing event attribute {0}")]
+    MissingAttribute(String),
+    #[error("Invalid event attribute {0}: {1}")]
+    InvalidAttribute(String, String),
+    #[error("Invalid transaction: {0}")]
+    InvalidTx(String),
+    #[error("Invalid transaction code hash: {0}")]
+    InvalidCodeHash(String),
+}
+
+impl DecodeError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DecodeError::MissingAttribute(_) => "missing_attribute",
+            DecodeError::InvalidAttribute(..) => "invalid_attribute",
+            DecodeError::InvalidTx(_) => "invalid_tx",
+            DecodeError::InvalidCodeHash(_) => "invalid_code_hash",
+        }
+    }
+}
+
 pub trait AsRetryError<T> {
     fn into_retry_error(self) -> Result<T, RetryError<std::io::Error>>;
 }
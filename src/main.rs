@@ -1,7 +1,6 @@
 pub mod alerts;
 pub mod checks;
 pub mod config;
-pub mod constants;
 pub mod error;
 pub mod log;
 pub mod metrics;
@@ -25,7 +24,7 @@ use tokio_retry2::{strategy::FixedInterval, RetryIf};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let config = AppConfig::parse();
+    let mut config = AppConfig::parse();
     config.log.init();
 
     tracing::info!(
@@ -37,6 +36,25 @@ async fn main() -> anyhow::Result<()> {
     rlimit::increase_nofile_limit(10240).unwrap();
     rlimit::increase_nofile_limit(u64::MAX).unwrap();
 
+    if config.get_config().discovery.enabled {
+        let rpc = rpc::Rpc::new(&config.rpc).await?;
+        match rpc.discover().await {
+            Ok(discovered) => {
+                config.discovered = discovered;
+                tracing::info!(
+                    "Discovered {} IBC channels, {} IBC clients without a transfer channel and {} tokens",
+                    config.discovered.channels.len(),
+                    config.discovered.clients.len(),
+                    config.discovered.tokens.len()
+                );
+            }
+            Err(e) => tracing::error!(
+                "Error discovering IBC channels and tokens, using the configured ones only: {}",
+                e
+            ),
+        }
+    }
+
     let tokens = config.get_config().tokens();
 
     tracing::info!("{:#?}", config.get_config());
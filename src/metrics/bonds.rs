@@ -12,7 +12,7 @@
 /// # TYPE namada_unbonds gauge
 /// namada_unbonds{epoch="2160",chain_id="local.300e84e1e16080e34547d538"} 0
 /// ```
-use crate::state::State;
+use crate::{shared::token, state::State};
 use anyhow::Result;
 use prometheus_exporter::prometheus::{GaugeVec, Opts, Registry};
 
@@ -37,10 +37,10 @@ impl MetricTrait for Bonds {
 
         self.bonds
             .with_label_values(&[&last_state.block.epoch.to_string()])
-            .set(last_state.bonds as f64);
+            .set(token::native_to_f64(last_state.bonds));
         self.unbonds
             .with_label_values(&[&last_state.block.epoch.to_string()])
-            .set(last_state.unbonds as f64);
+            .set(token::native_to_f64(last_state.unbonds));
     }
 }
 
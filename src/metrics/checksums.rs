@@ -0,0 +1,45 @@
+/// ## Transaction Code Hashes (tx_code_hash)
+/// An info metric exposing the code hash of each known transaction. The value is always 1 and the hash is
+/// exported as a label, so a change of label value reveals a protocol upgrade.
+///
+/// ### Example
+/// ```text
+/// # HELP tx_code_hash Code hash of each known transaction
+/// # TYPE tx_code_hash gauge
+/// tx_code_hash{name="tx_transfer",hash="4c5f..."} 1
+/// ```
+use crate::state::State;
+use anyhow::Result;
+use prometheus_exporter::prometheus::{GaugeVec, Opts, Registry};
+
+use super::MetricTrait;
+
+pub struct Checksums {
+    tx_code_hash: GaugeVec,
+}
+
+impl MetricTrait for Checksums {
+    fn register(&self, registry: &Registry) -> Result<()> {
+        registry.register(Box::new(self.tx_code_hash.clone()))?;
+        Ok(())
+    }
+
+    fn update(&self, state: &State) {
+        let last_state = state.last_block();
+
+        self.tx_code_hash.reset();
+        for (name, hash) in last_state.checksums.iter() {
+            self.tx_code_hash.with_label_values(&[name, hash]).set(1.0);
+        }
+    }
+}
+
+impl Default for Checksums {
+    fn default() -> Self {
+        let tx_code_hash_opts = Opts::new("tx_code_hash", "Code hash of each known transaction");
+        Self {
+            tx_code_hash: GaugeVec::new(tx_code_hash_opts, &["name", "hash"])
+                .expect("unable to create tx code hash metric"),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+/// ## Decode Errors (decode_errors)
+/// This metric counts the block events and transactions that could not be decoded and were skipped.
+/// A rising rate usually means the node was upgraded and emits data the monitor does not understand yet.
+///
+/// ### Example
+/// ```
+/// # HELP decode_errors Number of block pieces that could not be decoded
+/// # TYPE decode_errors counter
+/// decode_errors{kind="missing_attribute"} 3
+/// decode_errors{kind="invalid_tx"} 1
+/// ```
+use crate::state::State;
+use anyhow::Result;
+use prometheus_exporter::prometheus::{IntCounterVec, Opts, Registry};
+
+use super::MetricTrait;
+
+pub struct DecodeErrors {
+    /// Skipped block pieces by error kind
+    decode_errors: IntCounterVec,
+}
+
+impl MetricTrait for DecodeErrors {
+    fn register(&self, registry: &Registry) -> Result<()> {
+        registry.register(Box::new(self.decode_errors.clone()))?;
+        Ok(())
+    }
+
+    fn update(&self, state: &State) {
+        let last_state = state.last_block();
+
+        for error in &last_state.block.decode_errors {
+            self.decode_errors.with_label_values(&[error.kind()]).inc();
+        }
+    }
+}
+
+impl Default for DecodeErrors {
+    fn default() -> Self {
+        let decode_errors_opts = Opts::new(
+            "decode_errors",
+            "Number of block pieces that could not be decoded",
+        );
+        Self {
+            decode_errors: IntCounterVec::new(decode_errors_opts, &["kind"])
+                .expect("unable to create decode errors metric"),
+        }
+    }
+}
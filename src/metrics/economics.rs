@@ -0,0 +1,132 @@
+/// ## Inflation Minted (inflation_minted)
+/// The native token inflation minted at the start of each epoch, by source: PoS rewards, shielded
+/// (MASP) rewards and PGF inflation, in denominated units. PGF inflation is recomputed from the PGF
+/// rates since the chain does not record the minted amount.
+///
+/// ## Staking Ratio (staking_ratio)
+/// Total bonded stake of the current epoch divided by the effective native supply, i.e. the total
+/// supply without the PGF balance.
+///
+/// Both are only exported when the native token is configured in `[[token]]`.
+///
+/// ## Inflation Rates (pos_inflation_rate, staking_rewards_rate, pgf_inflation_rate, pgf_stewards_inflation_rate)
+/// Annual rates read from the chain: the PoS inflation rate and the estimated staking rewards rate
+/// derived from the last minted PoS inflation, and the PGF and steward inflation rates.
+///
+/// ### Example
+/// ```text
+/// # HELP inflation_minted Native inflation minted at the start of the epoch in denominated units
+/// # TYPE inflation_minted gauge
+/// inflation_minted{epoch="256",source="pos"} 12000.5
+/// # HELP staking_ratio Total bonded stake divided by the effective native supply
+/// # TYPE staking_ratio gauge
+/// staking_ratio 0.41
+/// # HELP pos_inflation_rate Annual PoS inflation rate
+/// # TYPE pos_inflation_rate gauge
+/// pos_inflation_rate 0.05
+/// ```
+use crate::state::State;
+use anyhow::Result;
+use prometheus_exporter::prometheus::{Gauge, GaugeVec, Opts, Registry};
+
+use super::MetricTrait;
+
+pub struct Economics {
+    inflation_minted: GaugeVec,
+    staking_ratio: Gauge,
+    pos_inflation_rate: Gauge,
+    staking_rewards_rate: Gauge,
+    pgf_inflation_rate: Gauge,
+    stewards_inflation_rate: Gauge,
+}
+
+impl MetricTrait for Economics {
+    fn register(&self, registry: &Registry) -> Result<()> {
+        registry.register(Box::new(self.inflation_minted.clone()))?;
+        registry.register(Box::new(self.staking_ratio.clone()))?;
+        registry.register(Box::new(self.pos_inflation_rate.clone()))?;
+        registry.register(Box::new(self.staking_rewards_rate.clone()))?;
+        registry.register(Box::new(self.pgf_inflation_rate.clone()))?;
+        registry.register(Box::new(self.stewards_inflation_rate.clone()))?;
+        Ok(())
+    }
+
+    fn update(&self, state: &State) {
+        let last_state = state.last_block();
+        let economics = &last_state.economics;
+        let native_token = &economics.native_token;
+
+        let epoch = last_state.block.epoch.to_string();
+        for inflation in last_state
+            .supply_audits
+            .iter()
+            .filter_map(|audit| audit.inflation)
+        {
+            for (source, amount) in [
+                ("pos", inflation.pos),
+                ("masp", inflation.masp),
+                ("pgf", inflation.pgf),
+            ] {
+                self.inflation_minted
+                    .with_label_values(&[&epoch, source])
+                    .set(state.to_f64(native_token, amount));
+            }
+        }
+
+        if let Some(supply) = last_state
+            .supplies
+            .iter()
+            .find(|supply| &supply.token == native_token)
+        {
+            let effective = state.to_f64(native_token, supply.effective);
+            if effective > 0.0 {
+                self.staking_ratio
+                    .set(state.to_f64(native_token, economics.total_bonded) / effective);
+            }
+        }
+
+        self.pos_inflation_rate.set(economics.pos_inflation_rate);
+        self.staking_rewards_rate
+            .set(economics.staking_rewards_rate);
+        self.pgf_inflation_rate.set(economics.pgf_inflation_rate);
+        self.stewards_inflation_rate
+            .set(economics.stewards_inflation_rate);
+    }
+}
+
+impl Default for Economics {
+    fn default() -> Self {
+        let inflation_minted_opts = Opts::new(
+            "inflation_minted",
+            "Native inflation minted at the start of the epoch in denominated units",
+        );
+        let staking_ratio_opts = Opts::new(
+            "staking_ratio",
+            "Total bonded stake divided by the effective native supply",
+        );
+        let pos_inflation_rate_opts = Opts::new("pos_inflation_rate", "Annual PoS inflation rate");
+        let staking_rewards_rate_opts = Opts::new(
+            "staking_rewards_rate",
+            "Estimated annual staking rewards rate",
+        );
+        let pgf_inflation_rate_opts = Opts::new("pgf_inflation_rate", "Annual PGF inflation rate");
+        let stewards_inflation_rate_opts = Opts::new(
+            "pgf_stewards_inflation_rate",
+            "Annual PGF steward inflation rate",
+        );
+        Self {
+            inflation_minted: GaugeVec::new(inflation_minted_opts, &["epoch", "source"])
+                .expect("unable to create inflation minted metric"),
+            staking_ratio: Gauge::with_opts(staking_ratio_opts)
+                .expect("unable to create staking ratio metric"),
+            pos_inflation_rate: Gauge::with_opts(pos_inflation_rate_opts)
+                .expect("unable to create pos inflation rate metric"),
+            staking_rewards_rate: Gauge::with_opts(staking_rewards_rate_opts)
+                .expect("unable to create staking rewards rate metric"),
+            pgf_inflation_rate: Gauge::with_opts(pgf_inflation_rate_opts)
+                .expect("unable to create pgf inflation rate metric"),
+            stewards_inflation_rate: Gauge::with_opts(stewards_inflation_rate_opts)
+                .expect("unable to create pgf stewards inflation rate metric"),
+        }
+    }
+}
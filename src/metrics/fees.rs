@@ -2,7 +2,12 @@
 /// This metric tracks the total transaction fees paid per token. It helps monitor the gas costs of transactions on
 /// the network, providing insight into network congestion and transaction fee trends.
 /// * The metric is a counter, meaning it only increases over time.
-/// * Fees are labeled by the token used for gas payments.
+/// * Fees are labeled by the token used for gas payments and expressed in the token's denominated units.
+/// * Fees are the amounts actually charged (gas used times gas price), not the declared maximum.
+///
+/// ## Top Fee Payers Metric. (fees_top_payers)
+/// The accounts that paid the most fees over the monitored window, labeled by payer and token.
+/// Only the top spenders are exported and the gauge is reset on every update.
 ///
 /// ### Example
 /// ```
@@ -10,23 +15,33 @@
 /// # TYPE namada_fees counter
 /// namada_fees{token="tnam1q9gr66cvu4hrzm0sd5kmlnjje82gs3xlfg3v6nu7",chain_id="housefire-alpaca.cc0d3e0c033be"} 0.5845009999999999
 /// namada_fees{token="tnam1q9gr66cvu4hrzm0sd5kmlnjje82gs3xlfg3v6nu7",chain_id="housefire-alpaca.cc0d3e0c033be"} 0.154409
+/// # HELP namada_fees_top_payers Fees charged to the top fee payers over the monitored window
+/// # TYPE namada_fees_top_payers gauge
+/// namada_fees_top_payers{payer="tnam1qz...",token="tnam1q9gr66cvu4hrzm0sd5kmlnjje82gs3xlfg3v6nu7",chain_id="housefire-alpaca.cc0d3e0c033be"} 12.5
 /// ```
-use crate::state::State;
-use prometheus_exporter::prometheus::{CounterVec, HistogramOpts, HistogramVec, Opts, Registry};
+use crate::{shared::token, state::State};
+use prometheus_exporter::prometheus::{
+    CounterVec, GaugeVec, HistogramOpts, HistogramVec, Opts, Registry,
+};
 
 use super::MetricTrait;
 
+const TOP_PAYERS: usize = 10;
+
 pub struct Fees {
     /// fees counters
     fees: CounterVec,
     /// histogram of fees by tx
     fees_by_tx: HistogramVec,
+    /// fees charged to the top payers
+    fees_top_payers: GaugeVec,
 }
 
 impl MetricTrait for Fees {
     fn register(&self, registry: &Registry) -> anyhow::Result<()> {
         registry.register(Box::new(self.fees.clone()))?;
         registry.register(Box::new(self.fees_by_tx.clone()))?;
+        registry.register(Box::new(self.fees_top_payers.clone()))?;
         Ok(())
     }
 
@@ -35,21 +50,31 @@ impl MetricTrait for Fees {
 
         let block_height = last_state.block.height.to_string();
         for tx in &last_state.block.transactions {
-            let amount_per_gas = tx.fee.amount_per_gas_unit.parse::<f64>();
-            let gas_limit = tx.fee.gas.parse::<f64>();
-
-            let fee = match (amount_per_gas, gas_limit) {
-                (Ok(amount_per_gas), Ok(gas_limit)) => amount_per_gas * gas_limit,
-                _ => continue,
+            let fee = match tx.fee.charged_fee() {
+                Some(fee) => token::to_f64(&fee),
+                None => continue,
             };
 
             self.fees
-                .with_label_values(&[&tx.fee.gas_token, &block_height])
+                .with_label_values(&[state.token_name(&tx.fee.gas_token), &block_height])
                 .inc_by(fee);
             self.fees_by_tx
-                .with_label_values(&[&tx.fee.gas_token])
+                .with_label_values(&[state.token_name(&tx.fee.gas_token)])
                 .observe(fee);
         }
+
+        let mut payers = state
+            .charged_fees_by_payer()
+            .into_iter()
+            .collect::<Vec<_>>();
+        payers.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        self.fees_top_payers.reset();
+        for ((payer, token), fee) in payers.into_iter().take(TOP_PAYERS) {
+            self.fees_top_payers
+                .with_label_values(&[&payer, state.token_name(&token)])
+                .set(fee);
+        }
     }
 }
 
@@ -66,6 +91,17 @@ impl Default for Fees {
         let fees_by_tx = HistogramVec::new(fees_by_tx_opts, &["token"])
             .expect("unable to create histogram vec for transaction fees by tx");
 
-        Self { fees, fees_by_tx }
+        let fees_top_payers_opts = Opts::new(
+            "fees_top_payers",
+            "Fees charged to the top fee payers over the monitored window",
+        );
+        let fees_top_payers = GaugeVec::new(fees_top_payers_opts, &["payer", "token"])
+            .expect("unable to create gauge vector for top fee payers");
+
+        Self {
+            fees,
+            fees_by_tx,
+            fees_top_payers,
+        }
     }
 }
@@ -0,0 +1,75 @@
+/// ## Governance Proposals (active_proposals, proposal_tally)
+/// These metrics track the governance proposals that have not been activated yet, and the running tally
+/// of each proposal that reached its voting period. Tallies are expressed as a share of the total voting
+/// power and labeled by proposal id and vote. Gauges are reset on every update.
+///
+/// ### Example
+/// ```text
+/// # HELP active_proposals Governance proposals not activated yet
+/// # TYPE active_proposals gauge
+/// active_proposals 2
+/// # HELP proposal_tally Share of the total voting power per proposal and vote
+/// # TYPE proposal_tally gauge
+/// proposal_tally{proposal_id="12",vote="yay"} 0.54
+/// proposal_tally{proposal_id="12",vote="nay"} 0.02
+/// proposal_tally{proposal_id="12",vote="abstain"} 0.01
+/// ```
+use crate::state::State;
+use anyhow::Result;
+use prometheus_exporter::prometheus::{Gauge, GaugeVec, Opts, Registry};
+
+use super::MetricTrait;
+
+pub struct Governance {
+    active_proposals: Gauge,
+    proposal_tally: GaugeVec,
+}
+
+impl MetricTrait for Governance {
+    fn register(&self, registry: &Registry) -> Result<()> {
+        registry.register(Box::new(self.active_proposals.clone()))?;
+        registry.register(Box::new(self.proposal_tally.clone()))?;
+        Ok(())
+    }
+
+    fn update(&self, state: &State) {
+        let last_state = state.last_block();
+        let proposals = &last_state.governance.proposals;
+
+        self.active_proposals.set(proposals.len() as f64);
+
+        self.proposal_tally.reset();
+        for (id, proposal) in proposals {
+            let Some(tally) = &proposal.tally else {
+                continue;
+            };
+            let id = id.to_string();
+            for (vote, votes) in [
+                ("yay", tally.yay),
+                ("nay", tally.nay),
+                ("abstain", tally.abstain),
+            ] {
+                self.proposal_tally
+                    .with_label_values(&[&id, vote])
+                    .set(tally.ratio(votes));
+            }
+        }
+    }
+}
+
+impl Default for Governance {
+    fn default() -> Self {
+        let active_proposals_opts =
+            Opts::new("active_proposals", "Governance proposals not activated yet");
+        let proposal_tally_opts = Opts::new(
+            "proposal_tally",
+            "Share of the total voting power per proposal and vote",
+        );
+        Self {
+            active_proposals: Gauge::with_opts(active_proposals_opts)
+                .expect("unable to create active proposals metric"),
+            proposal_tally: GaugeVec::new(proposal_tally_opts, &["proposal_id", "vote"])
+                .expect("unable to create proposal tally metric"),
+        }
+    }
+}
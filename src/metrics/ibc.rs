@@ -1,14 +1,69 @@
-use prometheus_exporter::prometheus::GaugeVec;
+/// ## IBC Token Limit (ibc_token_limit)
+/// The IBC minting limit of each configured token, in denominated units.
+///
+/// ## IBC Throughput (ibc_throughput_limit, ibc_throughput_usage)
+/// The per-epoch IBC throughput limit of each configured token, in denominated units, and the
+/// share of it used in the current epoch. Namada resets the throughput at every epoch.
+///
+/// ## IBC Channel Health (ibc_channel_open, ibc_connection_open, ibc_client_expiry_seconds, ibc_client_last_update_seconds)
+/// Whether each configured channel and its connection are open, the seconds left until the light
+/// client behind them expires, and the seconds since a relayer last updated it. Entries without a
+/// channel only export the connection and client metrics.
+///
+/// ## IBC Pending Packets (ibc_pending_packets, ibc_oldest_pending_packet_seconds)
+/// Number of packets sent over each configured channel that were neither acknowledged nor timed
+/// out yet, and for how long the oldest of them has been pending.
+///
+/// ## IBC Channel Flows (ibc_channel_volume, ibc_channel_net_flow)
+/// Volume of fungible tokens moved over each IBC channel, from decoded IBC transfers (outbound)
+/// and received packets and refunds (inbound), in denominated units. The net flow is the inbound
+/// minus the outbound volume over the `[ibc_flow]` window, so a negative value means tokens are
+/// leaving Namada over that channel.
+///
+/// ### Example
+/// ```text
+/// # HELP ibc_channel_volume Volume moved over an IBC channel in denominated units
+/// # TYPE ibc_channel_volume counter
+/// ibc_channel_volume{channel="channel-0",direction="outbound",token="transfer/channel-0/uosmo"} 1500
+/// # HELP ibc_channel_net_flow Inbound minus outbound volume over an IBC channel in denominated units
+/// # TYPE ibc_channel_net_flow gauge
+/// ibc_channel_net_flow{channel="channel-0",token="transfer/channel-0/uosmo"} -1200
+/// ```
+use prometheus_exporter::prometheus::{CounterVec, GaugeVec, Opts};
+
+use crate::{config::AppConfig, state::Window};
 
 use super::MetricTrait;
 
 pub struct Ibc {
     limit: GaugeVec,
+    throughput_limit: GaugeVec,
+    throughput_usage: GaugeVec,
+    channel_volume: CounterVec,
+    channel_net_flow: GaugeVec,
+    channel_open: GaugeVec,
+    connection_open: GaugeVec,
+    client_expiry: GaugeVec,
+    client_last_update: GaugeVec,
+    pending_packets: GaugeVec,
+    oldest_pending_packet: GaugeVec,
+    /// Window over which the net flow is computed
+    net_flow_window: Window,
 }
 
 impl MetricTrait for Ibc {
     fn register(&self, registry: &prometheus_exporter::prometheus::Registry) -> anyhow::Result<()> {
         registry.register(Box::new(self.limit.clone()))?;
+        registry.register(Box::new(self.throughput_limit.clone()))?;
+        registry.register(Box::new(self.throughput_usage.clone()))?;
+        registry.register(Box::new(self.channel_volume.clone()))?;
+        registry.register(Box::new(self.channel_net_flow.clone()))?;
+        registry.register(Box::new(self.channel_open.clone()))?;
+        registry.register(Box::new(self.connection_open.clone()))?;
+        registry.register(Box::new(self.client_expiry.clone()))?;
+        registry.register(Box::new(self.client_last_update.clone()))?;
+        registry.register(Box::new(self.pending_packets.clone()))?;
+        registry.register(Box::new(self.oldest_pending_packet.clone()))?;
         Ok(())
     }
 
@@ -16,23 +71,150 @@ impl MetricTrait for Ibc {
         let last_state = state.last_block();
         let epoch = last_state.block.epoch.to_string();
 
-        for (token, limit) in last_state.mint_limit {
+        for (token, limit) in &last_state.ibc_limits {
+            let name = state.token_name(token);
             self.limit
-                .with_label_values(&[&epoch, &token])
-                .set(limit as f64);
+                .with_label_values(&[&epoch, name])
+                .set(state.to_f64(token, limit.mint_limit));
+            self.throughput_limit
+                .with_label_values(&[name])
+                .set(state.to_f64(token, limit.throughput_limit));
+            if let Some(usage) = limit.throughput_usage() {
+                self.throughput_usage.with_label_values(&[name]).set(usage);
+            }
+        }
+
+        for flow in last_state.ibc_flows() {
+            self.channel_volume
+                .with_label_values(&[
+                    &flow.channel,
+                    state.token_name(&flow.token),
+                    &flow.direction.to_string(),
+                ])
+                .inc_by(state.to_f64(&flow.token, flow.amount));
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        for channel in &last_state.ibc_channels {
+            self.connection_open
+                .with_label_values(&[&channel.alias, &channel.connection_id])
+                .set(if channel.connection_open { 1.0 } else { 0.0 });
+            if let Some(seconds) = channel.seconds_until_expiry(now) {
+                self.client_expiry
+                    .with_label_values(&[&channel.alias, &channel.client_id])
+                    .set(seconds as f64);
+            }
+            if let Some(seconds) = channel.seconds_since_update(now) {
+                self.client_last_update
+                    .with_label_values(&[&channel.alias, &channel.client_id])
+                    .set(seconds as f64);
+            }
+
+            // Connections monitored without a channel have no channel state or packets
+            let Some(channel_id) = &channel.channel_id else {
+                continue;
+            };
+            self.channel_open
+                .with_label_values(&[&channel.alias, channel_id])
+                .set(if channel.channel_open { 1.0 } else { 0.0 });
+            self.pending_packets
+                .with_label_values(&[&channel.alias, channel_id])
+                .set(channel.pending_packets.len() as f64);
+            let oldest = channel
+                .pending_packets
+                .values()
+                .min()
+                .map(|first_seen| last_state.block.timestamp - first_seen)
+                .unwrap_or_default();
+            self.oldest_pending_packet
+                .with_label_values(&[&channel.alias, channel_id])
+                .set(oldest as f64);
+        }
+
+        self.channel_net_flow.reset();
+        for ((channel, token), volume) in state.ibc_volumes(self.net_flow_window) {
+            let net_flow =
+                state.to_f64(&token, volume.inbound) - state.to_f64(&token, volume.outbound);
+            self.channel_net_flow
+                .with_label_values(&[&channel, state.token_name(&token)])
+                .set(net_flow);
         }
     }
 }
 
-impl Default for Ibc {
-    fn default() -> Self {
-        let limit_opts = prometheus_exporter::prometheus::Opts::new(
+impl Ibc {
+    pub fn new(config: &AppConfig) -> Self {
+        let limit_opts = Opts::new(
             "ibc_token_limit",
-            "IBC token minting limit",
+            "IBC token minting limit in denominated units",
+        );
+        let throughput_limit_opts = Opts::new(
+            "ibc_throughput_limit",
+            "IBC per-epoch throughput limit in denominated units",
+        );
+        let throughput_usage_opts = Opts::new(
+            "ibc_throughput_usage",
+            "Share of the IBC per-epoch throughput limit used in the current epoch",
+        );
+        let channel_volume_opts = Opts::new(
+            "ibc_channel_volume",
+            "Volume moved over an IBC channel in denominated units",
+        );
+        let channel_net_flow_opts = Opts::new(
+            "ibc_channel_net_flow",
+            "Inbound minus outbound volume over an IBC channel in denominated units",
+        );
+        let channel_open_opts = Opts::new(
+            "ibc_channel_open",
+            "Whether the configured IBC channel is open",
+        );
+        let connection_open_opts = Opts::new(
+            "ibc_connection_open",
+            "Whether the connection of the configured IBC channel is open",
+        );
+        let client_expiry_opts = Opts::new(
+            "ibc_client_expiry_seconds",
+            "Seconds until the IBC light client expires",
+        );
+        let client_last_update_opts = Opts::new(
+            "ibc_client_last_update_seconds",
+            "Seconds since the IBC light client was last updated",
+        );
+        let pending_packets_opts = Opts::new(
+            "ibc_pending_packets",
+            "Packets sent over the IBC channel that were neither acknowledged nor timed out",
+        );
+        let oldest_pending_packet_opts = Opts::new(
+            "ibc_oldest_pending_packet_seconds",
+            "Seconds the oldest pending packet of the IBC channel has been waiting",
         );
         Self {
+            pending_packets: GaugeVec::new(pending_packets_opts, &["alias", "channel"])
+                .expect("unable to create ibc pending packets metric"),
+            oldest_pending_packet: GaugeVec::new(oldest_pending_packet_opts, &["alias", "channel"])
+                .expect("unable to create ibc oldest pending packet metric"),
+            channel_open: GaugeVec::new(channel_open_opts, &["alias", "channel"])
+                .expect("unable to create ibc channel open metric"),
+            connection_open: GaugeVec::new(connection_open_opts, &["alias", "connection"])
+                .expect("unable to create ibc connection open metric"),
+            client_expiry: GaugeVec::new(client_expiry_opts, &["alias", "client"])
+                .expect("unable to create ibc client expiry metric"),
+            client_last_update: GaugeVec::new(client_last_update_opts, &["alias", "client"])
+                .expect("unable to create ibc client last update metric"),
             limit: GaugeVec::new(limit_opts, &["epoch", "token"])
                 .expect("unable to create ibc token limit metric"),
+            throughput_limit: GaugeVec::new(throughput_limit_opts, &["token"])
+                .expect("unable to create ibc throughput limit metric"),
+            throughput_usage: GaugeVec::new(throughput_usage_opts, &["token"])
+                .expect("unable to create ibc throughput usage metric"),
+            channel_volume: CounterVec::new(
+                channel_volume_opts,
+                &["channel", "token", "direction"],
+            )
+            .expect("unable to create ibc channel volume metric"),
+            channel_net_flow: GaugeVec::new(channel_net_flow_opts, &["channel", "token"])
+                .expect("unable to create ibc channel net flow metric"),
+            net_flow_window: Window::Seconds(config.get_config().ibc_flow.window_seconds),
         }
     }
 }
@@ -1,29 +1,43 @@
 mod block;
 mod bonds;
+mod checksums;
+mod decode_errors;
+mod economics;
 mod epoch;
 mod fees;
+mod governance;
 mod ibc;
+mod parameters;
 mod signatures;
 mod slashes;
 mod token_total_supply;
 mod transactions;
 mod transfers;
+mod uptime;
 mod validator;
+mod validator_set;
 mod voting_power;
 
 use std::{collections::HashMap, net::SocketAddr};
 
 use block::Block;
 use bonds::Bonds;
+use checksums::Checksums;
+use decode_errors::DecodeErrors;
+use economics::Economics;
 use epoch::Epoch;
 use fees::Fees;
+use governance::Governance;
 use ibc::Ibc;
+use parameters::Parameters;
 use signatures::Signatures;
 use slashes::Slashes;
 use token_total_supply::TokenTotalSupply;
 use transactions::Transactions;
 use transfers::Transfers;
+use uptime::Uptime;
 use validator::ValidatorState;
+use validator_set::ValidatorSet;
 use voting_power::VotingPower;
 
 use crate::{config::AppConfig, state::State};
@@ -52,7 +66,7 @@ impl MetricsExporter {
         )
         .expect("Failed to create registry");
 
-        let metrics = Self::default_metrics();
+        let metrics = Self::default_metrics(config);
 
         for metric in &metrics {
             metric
@@ -67,7 +81,7 @@ impl MetricsExporter {
         }
     }
 
-    pub fn default_metrics() -> Vec<Box<dyn MetricTrait>> {
+    pub fn default_metrics(config: &AppConfig) -> Vec<Box<dyn MetricTrait>> {
         vec![
             Box::<Block>::default() as Box<dyn MetricTrait>,
             Box::<Bonds>::default() as Box<dyn MetricTrait>,
@@ -80,7 +94,14 @@ impl MetricsExporter {
             Box::<Signatures>::default() as Box<dyn MetricTrait>,
             Box::<Slashes>::default() as Box<dyn MetricTrait>,
             Box::<ValidatorState>::default() as Box<dyn MetricTrait>,
-            Box::<Ibc>::default() as Box<dyn MetricTrait>,
+            Box::new(Ibc::new(config)) as Box<dyn MetricTrait>,
+            Box::<DecodeErrors>::default() as Box<dyn MetricTrait>,
+            Box::<Uptime>::default() as Box<dyn MetricTrait>,
+            Box::new(ValidatorSet::new(config)) as Box<dyn MetricTrait>,
+            Box::<Governance>::default() as Box<dyn MetricTrait>,
+            Box::<Checksums>::default() as Box<dyn MetricTrait>,
+            Box::<Parameters>::default() as Box<dyn MetricTrait>,
+            Box::<Economics>::default() as Box<dyn MetricTrait>,
         ]
     }
 
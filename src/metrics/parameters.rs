@@ -0,0 +1,50 @@
+/// ## Protocol Parameters (protocol_parameter)
+/// This metric exports the numeric protocol parameters read from storage every epoch: block gas and size
+/// limits, epoch duration, gas costs, PoS parameters and the IBC rate limits of the configured tokens.
+/// Amounts are raw token amounts and parameters are labeled by name.
+///
+/// ### Example
+/// ```text
+/// # HELP protocol_parameter Numeric protocol parameters
+/// # TYPE protocol_parameter gauge
+/// protocol_parameter{name="max_block_gas"} 3000000
+/// protocol_parameter{name="pos.max_validator_slots"} 257
+/// ```
+use crate::state::State;
+use anyhow::Result;
+use prometheus_exporter::prometheus::{GaugeVec, Opts, Registry};
+
+use super::MetricTrait;
+
+pub struct Parameters {
+    protocol_parameter: GaugeVec,
+}
+
+impl MetricTrait for Parameters {
+    fn register(&self, registry: &Registry) -> Result<()> {
+        registry.register(Box::new(self.protocol_parameter.clone()))?;
+        Ok(())
+    }
+
+    fn update(&self, state: &State) {
+        let last_state = state.last_block();
+
+        self.protocol_parameter.reset();
+        for (name, value) in last_state.parameters.numeric() {
+            self.protocol_parameter
+                .with_label_values(&[name])
+                .set(value);
+        }
+    }
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        let protocol_parameter_opts =
+            Opts::new("protocol_parameter", "Numeric protocol parameters");
+        Self {
+            protocol_parameter: GaugeVec::new(protocol_parameter_opts, &["name"])
+                .expect("unable to create protocol parameter metric"),
+        }
+    }
+}
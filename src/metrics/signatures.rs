@@ -21,20 +21,33 @@
 /// signatures_sum 0
 /// signatures_count 0
 /// ```
+///
+/// ## Block Proposals Counter (block_proposals)
+/// This metric counts the blocks proposed by each validator, labeled by the Namada validator address.
+///
+/// ### Example
+/// ```text
+/// # HELP block_proposals Number of blocks proposed per validator
+/// # TYPE block_proposals counter
+/// block_proposals{validator="tnam1q..."} 12
+/// ```
 use crate::state::State;
 use anyhow::Result;
-use prometheus_exporter::prometheus::{GaugeVec, Opts, Registry};
+use prometheus_exporter::prometheus::{GaugeVec, IntCounterVec, Opts, Registry};
 
 use super::MetricTrait;
 
 pub struct Signatures {
     /// GaugeVec to track the number of signatures in the lastest block height
     signatures: GaugeVec,
+    /// IntCounterVec to track the number of blocks proposed by each validator
+    block_proposals: IntCounterVec,
 }
 
 impl MetricTrait for Signatures {
     fn register(&self, registry: &Registry) -> Result<()> {
         registry.register(Box::new(self.signatures.clone()))?;
+        registry.register(Box::new(self.block_proposals.clone()))?;
         Ok(())
     }
 
@@ -52,6 +65,10 @@ impl MetricTrait for Signatures {
         self.signatures
             .with_label_values(&[&last_state.block.height.to_string()])
             .set(total_signatures as f64);
+
+        let proposer =
+            last_state.validator_name(&last_state.block.block.header.proposer_address.to_string());
+        self.block_proposals.with_label_values(&[&proposer]).inc();
     }
 }
 
@@ -61,9 +78,13 @@ impl Default for Signatures {
             "block_signatures",
             "Number of validators signatures per block",
         );
+        let block_proposals_opts =
+            Opts::new("block_proposals", "Number of blocks proposed per validator");
         Self {
             signatures: GaugeVec::new(signature_opts, &["height"])
                 .expect("unable to create signatures metric"),
+            block_proposals: IntCounterVec::new(block_proposals_opts, &["validator"])
+                .expect("unable to create block proposals metric"),
         }
     }
 }
@@ -20,19 +20,37 @@
 /// slashes_sum 0
 /// slashes_count 0
 /// ```
-use crate::state::State;
+///
+/// ## Validator Slashes (validator_slashes, validator_slashed_stake)
+/// The PoS slashes enqueued for the validators named in the block evidence, by validator and slash
+/// type, and the stake each slashed validator had at the infraction epoch.
+///
+/// ### Example
+/// ```text
+/// # HELP validator_slashes Number of PoS slashes enqueued per validator
+/// # TYPE validator_slashes counter
+/// validator_slashes{type="duplicate_vote",validator="tnam1q..."} 1
+/// # HELP validator_slashed_stake Stake of the validator at the infraction epoch of its last slash
+/// # TYPE validator_slashed_stake gauge
+/// validator_slashed_stake{validator="tnam1q..."} 1000000
+/// ```
+use crate::{shared::token, state::State};
 use anyhow::Result;
-use prometheus_exporter::prometheus::{GaugeVec, Opts, Registry};
+use prometheus_exporter::prometheus::{GaugeVec, IntCounterVec, Opts, Registry};
 
 use super::MetricTrait;
 
 pub struct Slashes {
     slashes: GaugeVec,
+    validator_slashes: IntCounterVec,
+    validator_slashed_stake: GaugeVec,
 }
 
 impl MetricTrait for Slashes {
     fn register(&self, registry: &Registry) -> Result<()> {
         registry.register(Box::new(self.slashes.clone()))?;
+        registry.register(Box::new(self.validator_slashes.clone()))?;
+        registry.register(Box::new(self.validator_slashed_stake.clone()))?;
         Ok(())
     }
 
@@ -43,15 +61,36 @@ impl MetricTrait for Slashes {
         self.slashes
             .with_label_values(&[&last_state.block.epoch.to_string()])
             .set(total_slashes as f64);
+
+        for slash in &last_state.slashes {
+            self.validator_slashes
+                .with_label_values(&[&slash.validator, slash.label()])
+                .inc();
+            self.validator_slashed_stake
+                .with_label_values(&[&slash.validator])
+                .set(token::native_to_f64(slash.stake));
+        }
     }
 }
 
 impl Default for Slashes {
     fn default() -> Self {
         let slashes_opts = Opts::new("slashes", "Number of slashes per epoch");
+        let validator_slashes_opts = Opts::new(
+            "validator_slashes",
+            "Number of PoS slashes enqueued per validator",
+        );
+        let validator_slashed_stake_opts = Opts::new(
+            "validator_slashed_stake",
+            "Stake of the validator at the infraction epoch of its last slash",
+        );
         Self {
             slashes: GaugeVec::new(slashes_opts, &["epoch"])
                 .expect("unable to create slashes metric"),
+            validator_slashes: IntCounterVec::new(validator_slashes_opts, &["validator", "type"])
+                .expect("unable to create validator slashes metric"),
+            validator_slashed_stake: GaugeVec::new(validator_slashed_stake_opts, &["validator"])
+                .expect("unable to create validator slashed stake metric"),
         }
     }
 }
@@ -1,6 +1,8 @@
-/// ## Total Supply of Native Token Metric. token_total_supply
-/// This metric tracks the total supply of the native token on the Namada blockchain.
+/// ## Total Supply of Native Token Metric. token_total_supply, token_effective_supply
+/// This metric tracks the total supply of the native token on the Namada blockchain, in denominated units.
 /// * token_total_supply: A monotonic counter that records the latest total supply of the native token.
+/// * token_effective_supply: The total supply without the PGF balance for the native token, equal to
+///   the total supply for other tokens.
 ///
 /// ### Example
 /// ```
@@ -17,11 +19,14 @@ use super::MetricTrait;
 pub struct TokenTotalSupply {
     /// The total supply native token
     pub token_total_supply: GaugeVec,
+    /// The effective supply, excluding the PGF balance
+    pub token_effective_supply: GaugeVec,
 }
 
 impl MetricTrait for TokenTotalSupply {
     fn register(&self, registry: &Registry) -> Result<()> {
         registry.register(Box::new(self.token_total_supply.clone()))?;
+        registry.register(Box::new(self.token_effective_supply.clone()))?;
         Ok(())
     }
 
@@ -30,8 +35,17 @@ impl MetricTrait for TokenTotalSupply {
 
         for supply in &last_state.supplies {
             self.token_total_supply
-                .with_label_values(&[&supply.token, &last_state.block.epoch.to_string()])
-                .set(supply.total as f64);
+                .with_label_values(&[
+                    state.token_name(&supply.token),
+                    &last_state.block.epoch.to_string(),
+                ])
+                .set(state.to_f64(&supply.token, supply.total));
+            self.token_effective_supply
+                .with_label_values(&[
+                    state.token_name(&supply.token),
+                    &last_state.block.epoch.to_string(),
+                ])
+                .set(state.to_f64(&supply.token, supply.effective));
         }
     }
 }
@@ -40,9 +54,15 @@ impl Default for TokenTotalSupply {
     fn default() -> Self {
         let token_total_supply_opts =
             Opts::new("token_total_supply", "The token total supply per epoch");
+        let token_effective_supply_opts = Opts::new(
+            "token_effective_supply",
+            "The token effective supply per epoch, without the PGF balance",
+        );
         Self {
             token_total_supply: GaugeVec::new(token_total_supply_opts, &["token", "epoch"])
                 .expect("unable to create token_total_supply metric"),
+            token_effective_supply: GaugeVec::new(token_effective_supply_opts, &["token", "epoch"])
+                .expect("unable to create token_effective_supply metric"),
         }
     }
 }
@@ -47,6 +47,22 @@
 /// transaction_kind{kind="bond", epoch="256", failed="false"} 15
 /// transaction_kind{kind="vote_proposal", epoch="256", failed="true"} 3
 /// ```
+///
+/// ### transaction_failure (CounterVec)
+/// Tracks why transactions fail, per transaction kind and epoch. Wrappers rejected as a whole are counted with kind "wrapper".
+///
+/// #### Labels:
+///         - kind: The inner transaction kind (as in transaction_kind), or "wrapper".
+///         - reason: One of wrapper_rejected, vp_rejected, execution_failed or unknown.
+///         - epoch: The epoch in which the transaction was included.
+///
+/// ### Example
+/// ```
+/// # HELP transaction_failure Transaction failure count by reason per epoch
+/// # TYPE transaction_failure counter
+/// transaction_failure{kind="transfer", reason="vp_rejected", epoch="256"} 4
+/// transaction_failure{kind="wrapper", reason="wrapper_rejected", epoch="256"} 1
+/// ```
 use crate::state::State;
 use anyhow::Result;
 use prometheus_exporter::prometheus::{
@@ -61,12 +77,15 @@ pub struct Transactions {
     transaction_batch_size: Histogram,
     /// inner transaction kind counter by epoch
     transaction_kind: GenericCounterVec<AtomicU64>,
+    /// failed transactions counter by kind, reason and epoch
+    transaction_failure: GenericCounterVec<AtomicU64>,
 }
 
 impl MetricTrait for Transactions {
     fn register(&self, registry: &Registry) -> Result<()> {
         registry.register(Box::new(self.transaction_batch_size.clone()))?;
         registry.register(Box::new(self.transaction_kind.clone()))?;
+        registry.register(Box::new(self.transaction_failure.clone()))?;
         Ok(())
     }
 
@@ -76,12 +95,22 @@ impl MetricTrait for Transactions {
         let epoch = last_state.block.epoch.to_string();
         for tx in &last_state.block.transactions {
             self.transaction_batch_size.observe(tx.inners.len() as f64);
+            if let Some(failure) = &tx.failure {
+                self.transaction_failure
+                    .with_label_values(&["wrapper", failure.category(), &epoch])
+                    .inc();
+            }
             for inner in &tx.inners {
                 let inner_kind = inner.kind.to_string();
                 let failed = !inner.was_applied;
                 self.transaction_kind
                     .with_label_values(&[&inner_kind, &failed.to_string(), &epoch])
                     .inc();
+                if let Some(failure) = &inner.failure {
+                    self.transaction_failure
+                        .with_label_values(&[&inner_kind, failure.category(), &epoch])
+                        .inc();
+                }
             }
         }
     }
@@ -103,9 +132,18 @@ impl Default for Transactions {
             IntCounterVec::new(transaction_kind_opts, &["kind", "failed", "epoch"])
                 .expect("unable to create int counter for transaction kinds");
 
+        let transaction_failure_opts = Opts::new(
+            "transaction_failure",
+            "Transaction failure count by reason per epoch",
+        );
+        let transaction_failure =
+            IntCounterVec::new(transaction_failure_opts, &["kind", "reason", "epoch"])
+                .expect("unable to create int counter for transaction failures");
+
         Self {
             transaction_batch_size,
             transaction_kind,
+            transaction_failure,
         }
     }
 }
@@ -1,6 +1,7 @@
 /// ## Transfer Amount (transfer_amount)
 /// This metric tracks the total amount of tokens transferred since the monitoring started.
 /// It helps monitor token movement trends and detect unusual transfer activity.
+/// Amounts are expressed in the token's denominated units.
 ///
 /// ### Example
 /// ```
@@ -30,8 +31,11 @@ impl MetricTrait for Transfers {
 
         for transfer in state.get_all_transfers() {
             self.transfer_amount
-                .with_label_values(&[&transfer.token, &last_state.block.epoch.to_string()])
-                .add(transfer.amount as f64);
+                .with_label_values(&[
+                    state.token_name(&transfer.token),
+                    &last_state.block.epoch.to_string(),
+                ])
+                .add(state.to_f64(&transfer.token, transfer.amount));
         }
     }
 }
@@ -0,0 +1,109 @@
+/// ## Validator Uptime (validator_uptime, validator_missed_blocks)
+/// These metrics track, for every consensus validator, the share of blocks it signed and the number of
+/// blocks it missed over the monitored block window. Signatures are read from each block's last commit.
+/// Validators are labeled by their Namada address and the gauges are reset on every update.
+///
+/// ### Example
+/// ```text
+/// # HELP validator_uptime Share of blocks signed by the validator over the monitored window
+/// # TYPE validator_uptime gauge
+/// validator_uptime{validator="tnam1q..."} 0.998
+/// # HELP validator_missed_blocks Blocks missed by the validator over the monitored window
+/// # TYPE validator_missed_blocks gauge
+/// validator_missed_blocks{validator="tnam1q..."} 14
+/// ```
+///
+/// ## Liveness Missed Votes (validator_liveness_missed_votes, liveness_missed_votes_threshold)
+/// The missed votes of each consensus validator as counted by the PoS liveness window, and the number of
+/// missed votes that gets a validator jailed.
+///
+/// ### Example
+/// ```text
+/// # HELP validator_liveness_missed_votes Missed votes within the PoS liveness window
+/// # TYPE validator_liveness_missed_votes gauge
+/// validator_liveness_missed_votes{validator="tnam1q..."} 120
+/// # HELP liveness_missed_votes_threshold Missed votes within the PoS liveness window that get a validator jailed
+/// # TYPE liveness_missed_votes_threshold gauge
+/// liveness_missed_votes_threshold 1000
+/// ```
+use crate::state::State;
+use anyhow::Result;
+use prometheus_exporter::prometheus::{Gauge, GaugeVec, Opts, Registry};
+
+use super::MetricTrait;
+
+pub struct Uptime {
+    validator_uptime: GaugeVec,
+    validator_missed_blocks: GaugeVec,
+    validator_liveness_missed_votes: GaugeVec,
+    liveness_missed_votes_threshold: Gauge,
+}
+
+impl MetricTrait for Uptime {
+    fn register(&self, registry: &Registry) -> Result<()> {
+        registry.register(Box::new(self.validator_uptime.clone()))?;
+        registry.register(Box::new(self.validator_missed_blocks.clone()))?;
+        registry.register(Box::new(self.validator_liveness_missed_votes.clone()))?;
+        registry.register(Box::new(self.liveness_missed_votes_threshold.clone()))?;
+        Ok(())
+    }
+
+    fn update(&self, state: &State) {
+        let stats = state.signing_stats(state.total_blocks());
+
+        self.validator_uptime.reset();
+        self.validator_missed_blocks.reset();
+        for (validator, stats) in stats {
+            self.validator_uptime
+                .with_label_values(&[&validator])
+                .set(stats.uptime());
+            self.validator_missed_blocks
+                .with_label_values(&[&validator])
+                .set(stats.missed as f64);
+        }
+
+        let last_state = state.last_block();
+        self.validator_liveness_missed_votes.reset();
+        for validator in last_state.consensus_validators() {
+            self.validator_liveness_missed_votes
+                .with_label_values(&[&validator.address])
+                .set(last_state.liveness.missed_votes(&validator.address) as f64);
+        }
+        self.liveness_missed_votes_threshold
+            .set(last_state.liveness.missed_votes_threshold as f64);
+    }
+}
+
+impl Default for Uptime {
+    fn default() -> Self {
+        let validator_uptime_opts = Opts::new(
+            "validator_uptime",
+            "Share of blocks signed by the validator over the monitored window",
+        );
+        let validator_missed_blocks_opts = Opts::new(
+            "validator_missed_blocks",
+            "Blocks missed by the validator over the monitored window",
+        );
+        let validator_liveness_missed_votes_opts = Opts::new(
+            "validator_liveness_missed_votes",
+            "Missed votes within the PoS liveness window",
+        );
+        let liveness_missed_votes_threshold_opts = Opts::new(
+            "liveness_missed_votes_threshold",
+            "Missed votes within the PoS liveness window that get a validator jailed",
+        );
+        Self {
+            validator_liveness_missed_votes: GaugeVec::new(
+                validator_liveness_missed_votes_opts,
+                &["validator"],
+            )
+            .expect("unable to create validator liveness missed votes metric"),
+            liveness_missed_votes_threshold: Gauge::with_opts(liveness_missed_votes_threshold_opts)
+                .expect("unable to create liveness missed votes threshold metric"),
+            validator_uptime: GaugeVec::new(validator_uptime_opts, &["validator"])
+                .expect("unable to create validator uptime metric"),
+            validator_missed_blocks: GaugeVec::new(validator_missed_blocks_opts, &["validator"])
+                .expect("unable to create validator missed blocks metric"),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+/// ## Validator Set Changes (validator_set_changes)
+/// This metric counts validator set events observed at epoch boundaries: validators joining the set,
+/// entering or leaving consensus, getting jailed or unjailed, becoming inactive or being reactivated.
+/// Events are labeled by kind and Namada validator address.
+///
+/// ## Voting Power Shift (validator_voting_power_shift)
+/// The relative voting power change of each validator whose voting power changed at the last epoch boundary
+/// by at least `pos.voting_power_shift_threshold`. The gauge is reset on every new epoch.
+///
+/// ### Example
+/// ```text
+/// # HELP validator_set_changes Validator set events at epoch boundaries
+/// # TYPE validator_set_changes counter
+/// validator_set_changes{kind="jailed",validator="tnam1q..."} 1
+/// # HELP validator_voting_power_shift Relative voting power change at the last epoch boundary
+/// # TYPE validator_voting_power_shift gauge
+/// validator_voting_power_shift{validator="tnam1q..."} -0.25
+/// ```
+use crate::{config::AppConfig, state::State};
+use anyhow::Result;
+use prometheus_exporter::prometheus::{GaugeVec, IntCounterVec, Opts, Registry};
+
+use super::MetricTrait;
+
+pub struct ValidatorSet {
+    validator_set_changes: IntCounterVec,
+    validator_voting_power_shift: GaugeVec,
+    voting_power_shift_threshold: f64,
+}
+
+impl MetricTrait for ValidatorSet {
+    fn register(&self, registry: &Registry) -> Result<()> {
+        registry.register(Box::new(self.validator_set_changes.clone()))?;
+        registry.register(Box::new(self.validator_voting_power_shift.clone()))?;
+        Ok(())
+    }
+
+    fn update(&self, state: &State) {
+        if state.last_block().block.epoch == state.prev_block().block.epoch {
+            return;
+        }
+
+        self.validator_voting_power_shift.reset();
+        for change in state.validator_set_changes() {
+            match change.voting_power_shift() {
+                Some(shift) if shift.abs() < self.voting_power_shift_threshold => (),
+                Some(shift) => self
+                    .validator_voting_power_shift
+                    .with_label_values(&[&change.address])
+                    .set(shift),
+                None => self
+                    .validator_set_changes
+                    .with_label_values(&[change.kind.label(), &change.address])
+                    .inc(),
+            }
+        }
+    }
+}
+
+impl ValidatorSet {
+    pub fn new(config: &AppConfig) -> Self {
+        let validator_set_changes_opts = Opts::new(
+            "validator_set_changes",
+            "Validator set events at epoch boundaries",
+        );
+        let validator_voting_power_shift_opts = Opts::new(
+            "validator_voting_power_shift",
+            "Relative voting power change at the last epoch boundary",
+        );
+        Self {
+            validator_set_changes: IntCounterVec::new(
+                validator_set_changes_opts,
+                &["kind", "validator"],
+            )
+            .expect("unable to create validator set changes metric"),
+            validator_voting_power_shift: GaugeVec::new(
+                validator_voting_power_shift_opts,
+                &["validator"],
+            )
+            .expect("unable to create validator voting power shift metric"),
+            voting_power_shift_threshold: config.get_config().pos.voting_power_shift_threshold,
+        }
+    }
+}
@@ -20,7 +20,7 @@
 /// total_voting_power 20
 ///
 /// ```
-use crate::state::State;
+use crate::{shared::token, state::State};
 use anyhow::Result;
 use prometheus_exporter::prometheus::{GaugeVec, Opts, Registry};
 
@@ -58,7 +58,7 @@ impl MetricTrait for VotingPower {
             .set(two_third_vp as f64);
         self.total_voting_power
             .with_label_values(&[&last_state.block.epoch.to_string()])
-            .set(state.total_voting_power() as f64);
+            .set(token::native_to_f64(state.total_voting_power()));
     }
 }
 
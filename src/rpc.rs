@@ -1,21 +1,42 @@
 use crate::shared::{
     checksums::Checksums,
+    discovery::{Discovered, DiscoveredChannel, DiscoveredClient, DiscoveredToken},
+    economics::Economics,
+    governance::{Proposal, Tally},
+    ibc::IbcChannelStatus,
+    liveness::Liveness,
     namada::{Address, Block, BlockResult, Epoch, Height, Validator},
-    supply::Supply,
+    parameters::Parameters,
+    slashes::SlashRecord,
+    supply::{Inflation, Supply},
+    validator_metadata::ValidatorMetadata,
 };
 use anyhow::Context;
 use futures::StreamExt;
 
 use crate::shared::client::Client as OwnClient;
+use namada_sdk::ibc::clients::tendermint::types::ClientState as TendermintClientState;
 use namada_sdk::tendermint::block::Height as TenderHeight;
 use namada_sdk::{
     address::Address as NamadaAddress,
+    borsh::BorshDeserialize,
+    chain::BlockHeight,
+    governance::{
+        pgf::storage::steward::StewardDetail, storage::proposal::StorageProposal, utils::Vote,
+    },
     hash::Hash,
+    key::tm_consensus_key_raw_hash,
     proof_of_stake::types::ValidatorState,
+    queries::RPC,
     rpc,
     state::{Epoch as NamadaEpoch, Key},
+    storage::{DbKeySeg, PrefixValue},
+    token::Amount,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
 };
-use std::str::FromStr;
 use tendermint_rpc::Client;
 
 pub struct Rpc {
@@ -125,11 +146,21 @@ impl Rpc {
         &self,
         validator: &NamadaAddress,
         epoch: Epoch,
-    ) -> anyhow::Result<u64> {
+    ) -> anyhow::Result<Amount> {
         let res = rpc::get_validator_stake(self.client.as_ref(), epoch.into(), validator).await;
         let stake = res.context("Should be able to query validator stake")?;
 
-        Ok(stake.raw_amount().as_u64())
+        Ok(stake)
+    }
+
+    pub async fn query_consensus_address(
+        &self,
+        validator: &NamadaAddress,
+    ) -> anyhow::Result<Option<String>> {
+        let res = rpc::query_validator_consensus_keys(self.client.as_ref(), validator).await;
+        let consensus_key = res.context("Should be able to query validator consensus key")?;
+
+        Ok(consensus_key.map(|key| tm_consensus_key_raw_hash(&key)))
     }
 
     pub async fn query_validators(&self, epoch: Epoch) -> anyhow::Result<Vec<Validator>> {
@@ -142,8 +173,10 @@ impl Rpc {
                 let state = self
                     .query_validator_state(&validator_address, epoch)
                     .await?;
+                let consensus_address = self.query_consensus_address(&validator_address).await?;
                 Ok::<_, anyhow::Error>(Validator {
                     address: validator_address.to_string(),
+                    consensus_address,
                     voting_power,
                     state,
                 })
@@ -170,13 +203,25 @@ impl Rpc {
             .map(|address| address.to_string())
     }
 
-    pub async fn query_total_supply(&self, native_token: &str) -> anyhow::Result<u64> {
+    pub async fn query_total_supply(&self, native_token: &str) -> anyhow::Result<Amount> {
         let address = NamadaAddress::from_str(native_token)
             .context("Should be able to convert string to address")?;
         let res = rpc::get_token_total_supply(self.client.as_ref(), &address).await;
 
         res.context("Should be able to query native token")
-            .map(|amount| amount.raw_amount().as_u64())
+    }
+
+    pub async fn query_token_denomination(&self, token: &str) -> anyhow::Result<Option<u8>> {
+        let address = NamadaAddress::from_str(token)
+            .context("Should be able to convert string to address")?;
+        let res = RPC
+            .vp()
+            .token()
+            .denomination(self.client.as_ref(), &address)
+            .await;
+
+        res.context("Should be able to query token denomination")
+            .map(|denom| denom.map(|denom| denom.0))
     }
 
     pub async fn query_max_block_time_estimate(&self) -> anyhow::Result<u64> {
@@ -186,7 +231,10 @@ impl Rpc {
             .map(|amount| amount.0)
     }
 
-    pub async fn query_future_bonds_and_unbonds(&self, epoch: Epoch) -> anyhow::Result<(u64, u64)> {
+    pub async fn query_future_bonds_and_unbonds(
+        &self,
+        epoch: Epoch,
+    ) -> anyhow::Result<(Amount, Amount)> {
         let pipeline_epoch = NamadaEpoch(epoch + 1);
         let res =
             rpc::enriched_bonds_and_unbonds(self.client.as_ref(), pipeline_epoch, &None, &None)
@@ -195,18 +243,523 @@ impl Rpc {
         res.context("Should be able to query bonds and unbonds")
             .map(|summary| {
                 (
-                    summary
-                        .bonds_total_active()
-                        .map(|amount| amount.raw_amount().as_u64())
-                        .unwrap_or(0),
-                    summary
-                        .unbonds_total_active()
-                        .map(|amount| amount.raw_amount().as_u64())
-                        .unwrap_or(0),
+                    summary.bonds_total_active().unwrap_or_default(),
+                    summary.unbonds_total_active().unwrap_or_default(),
                 )
             })
     }
 
+    /// Read the PoS liveness parameters, returning the liveness window and the number of
+    /// missed votes within that window that gets a validator jailed
+    pub async fn query_liveness_params(&self) -> anyhow::Result<(u64, u64)> {
+        let res = rpc::get_pos_params(self.client.as_ref()).await;
+        let params = res.context("Should be able to query PoS parameters")?.owned;
+
+        let liveness_threshold = params
+            .liveness_threshold
+            .to_string()
+            .parse::<f64>()
+            .context("Should be able to parse liveness threshold")?;
+        let window = params.liveness_window_check;
+        let missed_votes_threshold = ((1.0 - liveness_threshold) * window as f64).floor() as u64;
+
+        Ok((window, missed_votes_threshold))
+    }
+
+    pub async fn query_liveness_at_height(
+        &self,
+        height: Height,
+        window: u64,
+        missed_votes_threshold: u64,
+    ) -> anyhow::Result<Liveness> {
+        let key = namada_sdk::proof_of_stake::storage_key::liveness_sum_missed_votes_key();
+        let res = RPC
+            .shell()
+            .storage_prefix(
+                self.client.as_ref(),
+                None,
+                Some(BlockHeight(height)),
+                false,
+                &key,
+            )
+            .await
+            .context("Should be able to query liveness missed votes")?;
+
+        let missed_votes = res
+            .data
+            .into_iter()
+            .filter_map(|PrefixValue { key, value }| {
+                let address = match key.last() {
+                    Some(DbKeySeg::AddressSeg(address)) => address.to_string(),
+                    _ => return None,
+                };
+                let missed_votes = u64::try_from_slice(&value).ok()?;
+                Some((address, missed_votes))
+            })
+            .collect();
+
+        Ok(Liveness {
+            window,
+            missed_votes_threshold,
+            missed_votes,
+        })
+    }
+
+    /// Read the metadata of a validator as stored at the given height, along with the commission
+    /// rate in effect at the given epoch
+    pub async fn query_validator_metadata_at_height(
+        &self,
+        address: &str,
+        height: Height,
+        epoch: Epoch,
+    ) -> anyhow::Result<ValidatorMetadata> {
+        use namada_sdk::proof_of_stake::storage_key;
+
+        let validator = NamadaAddress::from_str(address).context("Should be a valid address")?;
+        let client = self.client.as_ref();
+
+        let read = |key: Key| async move {
+            let res = RPC
+                .shell()
+                .storage_value(client, None, Some(BlockHeight(height)), false, &key)
+                .await
+                .context("Should be able to query validator metadata")?;
+            anyhow::Ok(String::try_from_slice(&res.data).ok())
+        };
+
+        let commission = rpc::query_commission_rate(client, &validator, Some(NamadaEpoch(epoch)))
+            .await
+            .context("Should be able to query validator commission rate")?;
+        let commission_rate = commission
+            .commission_rate
+            .and_then(|rate| rate.to_string().parse::<f64>().ok());
+
+        Ok(ValidatorMetadata {
+            name: read(storage_key::validator_name_key(&validator)).await?,
+            email: read(storage_key::validator_email_key(&validator)).await?,
+            description: read(storage_key::validator_description_key(&validator)).await?,
+            website: read(storage_key::validator_website_key(&validator)).await?,
+            discord_handle: read(storage_key::validator_discord_key(&validator)).await?,
+            avatar: read(storage_key::validator_avatar_key(&validator)).await?,
+            commission_rate,
+        })
+    }
+
+    /// Slashes enqueued by PoS for the given validators at the end of the given block, with the
+    /// stake they had at the infraction epoch
+    pub async fn query_enqueued_slashes(
+        &self,
+        validators: &BTreeSet<String>,
+        epoch: Epoch,
+        height: Height,
+    ) -> anyhow::Result<Vec<SlashRecord>> {
+        use namada_sdk::{
+            proof_of_stake::{storage::enqueued_slashes_handle, storage_key, types::Slash},
+            storage::collections::{lazy_map::NestedSubKey, LazyCollection},
+        };
+
+        let res = RPC
+            .shell()
+            .storage_prefix(
+                self.client.as_ref(),
+                None,
+                Some(BlockHeight(height)),
+                false,
+                &storage_key::enqueued_slashes_key(),
+            )
+            .await
+            .context("Should be able to query enqueued slashes")?;
+
+        let handle = enqueued_slashes_handle().get_data_handler();
+        let mut slashes = vec![];
+        for PrefixValue { key, value } in res.data {
+            if !handle.is_data_sub_key(&key) {
+                continue;
+            }
+            let Ok(Some(NestedSubKey::Data {
+                key: processing_epoch,
+                nested_sub_key:
+                    NestedSubKey::Data {
+                        key: validator,
+                        nested_sub_key: _,
+                    },
+            })) = handle.is_valid_sub_key(&key)
+            else {
+                continue;
+            };
+            if processing_epoch.0 <= epoch || !validators.contains(&validator.to_string()) {
+                continue;
+            }
+            let Ok(slash) = Slash::try_from_slice(&value) else {
+                tracing::warn!("Skipping undecodable enqueued slash {}", key);
+                continue;
+            };
+            let stake = match self.query_stake(&validator, slash.epoch.0).await {
+                Ok(stake) => stake,
+                Err(e) => {
+                    tracing::error!("Error querying slashed stake of {}: {}", validator, e);
+                    Amount::zero()
+                }
+            };
+            slashes.push(SlashRecord {
+                validator: validator.to_string(),
+                kind: slash.r#type,
+                rate: slash.rate.to_string().parse::<f64>().unwrap_or_default(),
+                infraction_epoch: slash.epoch.0,
+                block_height: slash.block_height,
+                processing_epoch: processing_epoch.0,
+                stake,
+            });
+        }
+
+        Ok(slashes)
+    }
+
+    /// Id the next submitted governance proposal will get, as of the end of the given block
+    pub async fn query_proposal_counter(&self, height: Height) -> anyhow::Result<u64> {
+        let key = namada_sdk::governance::storage::keys::get_counter_key();
+
+        self.read_value_at_height::<u64>(&key, height)
+            .await?
+            .context("Should be able to query governance proposal counter")
+    }
+
+    /// A governance proposal as stored at the end of the given block
+    pub async fn query_proposal(
+        &self,
+        id: u64,
+        height: Height,
+    ) -> anyhow::Result<Option<Proposal>> {
+        Ok(self
+            .query_storage_proposal(id, height)
+            .await?
+            .map(Proposal::from))
+    }
+
+    /// Read a proposal from its storage keys, the way the protocol does
+    async fn query_storage_proposal(
+        &self,
+        id: u64,
+        height: Height,
+    ) -> anyhow::Result<Option<StorageProposal>> {
+        use namada_sdk::governance::storage::{keys as governance_keys, proposal::ProposalType};
+
+        let Some(proposal_type) = self
+            .read_value_at_height::<ProposalType>(
+                &governance_keys::get_proposal_type_key(id),
+                height,
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+        let proposal_type = match proposal_type {
+            ProposalType::DefaultWithWasm(_) => {
+                let code = self
+                    .read_value_at_height::<Vec<u8>>(
+                        &governance_keys::get_proposal_code_key(id),
+                        height,
+                    )
+                    .await?
+                    .unwrap_or_default();
+                ProposalType::DefaultWithWasm(Hash::sha256(code))
+            }
+            proposal_type => proposal_type,
+        };
+        let epoch = |key: Key| async move {
+            self.read_value_at_height::<NamadaEpoch>(&key, height)
+                .await?
+                .context("Should be able to query proposal epochs")
+        };
+
+        Ok(Some(StorageProposal {
+            id,
+            content: self
+                .read_value_at_height(&governance_keys::get_content_key(id), height)
+                .await?
+                .unwrap_or_default(),
+            author: self
+                .read_value_at_height(&governance_keys::get_author_key(id), height)
+                .await?
+                .context("Should be able to query proposal author")?,
+            r#type: proposal_type,
+            voting_start_epoch: epoch(governance_keys::get_voting_start_epoch_key(id)).await?,
+            voting_end_epoch: epoch(governance_keys::get_voting_end_epoch_key(id)).await?,
+            activation_epoch: epoch(governance_keys::get_activation_epoch_key(id)).await?,
+        }))
+    }
+
+    /// Votes cast on the given proposal by the end of the given block
+    async fn query_votes(&self, id: u64, height: Height) -> anyhow::Result<Vec<Vote>> {
+        use namada_sdk::governance::{storage::keys as governance_keys, ProposalVote};
+
+        let res = RPC
+            .shell()
+            .storage_prefix(
+                self.client.as_ref(),
+                None,
+                Some(BlockHeight(height)),
+                false,
+                &governance_keys::get_proposal_vote_prefix_key(id),
+            )
+            .await
+            .context("Should be able to query governance proposal votes")?;
+
+        Ok(res
+            .data
+            .into_iter()
+            .filter_map(|PrefixValue { key, value }| {
+                Some(Vote {
+                    validator: governance_keys::get_vote_delegation_address(&key)?.clone(),
+                    delegator: governance_keys::get_voter_address(&key)?.clone(),
+                    data: ProposalVote::try_from_slice(&value).ok()?,
+                })
+            })
+            .collect())
+    }
+
+    /// Validators that voted on the given proposal by the end of the given block
+    pub async fn query_proposal_voters(
+        &self,
+        id: u64,
+        height: Height,
+    ) -> anyhow::Result<BTreeSet<String>> {
+        Ok(self
+            .query_votes(id, height)
+            .await?
+            .into_iter()
+            .filter(|vote| vote.is_validator())
+            .map(|vote| vote.validator.to_string())
+            .collect())
+    }
+
+    /// Tally of a proposal at the end of the given block: the stored result once the voting
+    /// ended, otherwise the votes cast so far weighted by the stake of the given epoch. None
+    /// before the voting starts.
+    pub async fn query_proposal_tally(
+        &self,
+        id: u64,
+        epoch: Epoch,
+        height: Height,
+    ) -> anyhow::Result<Option<Tally>> {
+        use namada_sdk::governance::{
+            pgf::storage::keys as pgf_storage,
+            storage::keys as governance_keys,
+            utils::{compute_proposal_result, ProposalResult, ProposalVotes},
+        };
+
+        let client = self.client.as_ref();
+        if let Some(result) = self
+            .read_value_at_height::<ProposalResult>(
+                &governance_keys::get_proposal_result_key(id),
+                height,
+            )
+            .await?
+        {
+            return Ok(Some(Tally::from(result)));
+        }
+
+        let Some(proposal) = self.query_storage_proposal(id, height).await? else {
+            return Ok(None);
+        };
+        if epoch < proposal.voting_start_epoch.0 {
+            return Ok(None);
+        }
+        let is_steward = self
+            .read_value_at_height::<StewardDetail>(
+                &pgf_storage::stewards_handle().get_data_key(&proposal.author),
+                height,
+            )
+            .await?
+            .is_some();
+
+        let tally_epoch = NamadaEpoch(epoch);
+        let total_voting_power = rpc::get_total_active_voting_power(client, tally_epoch)
+            .await
+            .context("Should be able to query total active voting power")?;
+        let mut votes = ProposalVotes::default();
+        for vote in self.query_votes(id, height).await? {
+            if vote.is_validator() {
+                let voting_power = rpc::get_validator_stake(client, tally_epoch, &vote.validator)
+                    .await
+                    .context("Should be able to query validator stake")?;
+                votes.add_validator(&vote.validator, voting_power, vote.data);
+            } else {
+                let voting_power =
+                    rpc::get_bond_amount_at(client, &vote.delegator, &vote.validator, tally_epoch)
+                        .await
+                        .context("Should be able to query delegator bond")?;
+                votes.add_delegator(&vote.delegator, &vote.validator, voting_power, vote.data);
+            }
+        }
+        let result = compute_proposal_result(
+            votes,
+            total_voting_power,
+            proposal.get_tally_type(is_steward),
+        )
+        .context("Should be able to compute proposal result")?;
+
+        Ok(Some(Tally::from(result)))
+    }
+
+    /// Read a snapshot of the protocol, PoS and IBC rate limit parameters
+    pub async fn query_parameters(
+        &self,
+        tokens: &[String],
+        height: Height,
+    ) -> anyhow::Result<Parameters> {
+        use namada_sdk::{
+            ibc::{parameters::IbcParameters, storage as ibc_storage},
+            parameters::{storage as params_storage, EpochDuration},
+            proof_of_stake::{storage_key as pos_storage, OwnedPosParams},
+        };
+
+        let mut parameters = Parameters::default();
+
+        let u64_parameters = [
+            ("max_block_gas", params_storage::get_max_block_gas_key()),
+            (
+                "max_proposal_bytes",
+                params_storage::get_max_proposal_bytes_key(),
+            ),
+            ("epochs_per_year", params_storage::get_epochs_per_year_key()),
+            (
+                "masp_epoch_multiplier",
+                params_storage::get_masp_epoch_multiplier_key(),
+            ),
+            (
+                "masp_fee_payment_gas_limit",
+                params_storage::get_masp_fee_payment_gas_limit_key(),
+            ),
+            ("gas_scale", params_storage::get_gas_scale_key()),
+        ];
+        for (name, key) in u64_parameters {
+            if let Some(value) = self.read_parameter::<u64>(name, &key, height).await {
+                parameters.insert(name, value);
+            }
+        }
+
+        if let Some(max_tx_bytes) = self
+            .read_parameter::<u32>(
+                "max_tx_bytes",
+                &params_storage::get_max_tx_bytes_key(),
+                height,
+            )
+            .await
+        {
+            parameters.insert("max_tx_bytes", max_tx_bytes);
+        }
+
+        if let Some(epoch_duration) = self
+            .read_parameter::<EpochDuration>(
+                "epoch_duration",
+                &params_storage::get_epoch_duration_storage_key(),
+                height,
+            )
+            .await
+        {
+            parameters.insert("epoch_min_num_of_blocks", epoch_duration.min_num_of_blocks);
+            parameters.insert("epoch_min_duration", epoch_duration.min_duration.0);
+        }
+
+        if let Some(gas_cost) = self
+            .read_parameter::<BTreeMap<NamadaAddress, Amount>>(
+                "gas_cost",
+                &params_storage::get_gas_cost_key(),
+                height,
+            )
+            .await
+        {
+            for (token, amount) in gas_cost {
+                parameters.insert(format!("gas_cost.{}", token), amount.raw_amount());
+            }
+        }
+
+        if let Some(pos) = self
+            .read_parameter::<OwnedPosParams>("pos", &pos_storage::params_key(), height)
+            .await
+        {
+            parameters.insert("pos.max_validator_slots", pos.max_validator_slots);
+            parameters.insert("pos.pipeline_len", pos.pipeline_len);
+            parameters.insert("pos.unbonding_len", pos.unbonding_len);
+            parameters.insert("pos.tm_votes_per_token", pos.tm_votes_per_token);
+            parameters.insert("pos.block_proposer_reward", pos.block_proposer_reward);
+            parameters.insert("pos.block_vote_reward", pos.block_vote_reward);
+            parameters.insert("pos.max_inflation_rate", pos.max_inflation_rate);
+            parameters.insert("pos.target_staked_ratio", pos.target_staked_ratio);
+            parameters.insert(
+                "pos.duplicate_vote_min_slash_rate",
+                pos.duplicate_vote_min_slash_rate,
+            );
+            parameters.insert(
+                "pos.light_client_attack_min_slash_rate",
+                pos.light_client_attack_min_slash_rate,
+            );
+            parameters.insert(
+                "pos.cubic_slashing_window_length",
+                pos.cubic_slashing_window_length,
+            );
+            parameters.insert(
+                "pos.validator_stake_threshold",
+                pos.validator_stake_threshold.raw_amount(),
+            );
+            parameters.insert("pos.liveness_window_check", pos.liveness_window_check);
+            parameters.insert("pos.liveness_threshold", pos.liveness_threshold);
+            parameters.insert("pos.rewards_gain_p", pos.rewards_gain_p);
+            parameters.insert("pos.rewards_gain_d", pos.rewards_gain_d);
+        }
+
+        let default_rate_limits = self
+            .read_parameter::<IbcParameters>("ibc", &ibc_storage::params_key(), height)
+            .await
+            .map(|ibc_parameters| ibc_parameters.default_rate_limits);
+        for token in tokens {
+            let Ok(address) = NamadaAddress::from_str(token) else {
+                tracing::warn!(
+                    "Skipping IBC rate limits of invalid token address {}",
+                    token
+                );
+                continue;
+            };
+            let mint_limit = match self
+                .read_value_at_height::<Amount>(&ibc_storage::mint_limit_key(&address), height)
+                .await
+            {
+                Ok(limit) => limit.or(default_rate_limits.as_ref().map(|l| l.mint_limit)),
+                Err(e) => {
+                    tracing::warn!("Missing parameter ibc_mint_limit.{}: {}", token, e);
+                    None
+                }
+            };
+            let throughput_limit = match self
+                .read_value_at_height::<Amount>(
+                    &ibc_storage::throughput_limit_key(&address),
+                    height,
+                )
+                .await
+            {
+                Ok(limit) => limit.or(default_rate_limits
+                    .as_ref()
+                    .map(|l| l.throughput_per_epoch_limit)),
+                Err(e) => {
+                    tracing::warn!("Missing parameter ibc_throughput_limit.{}: {}", token, e);
+                    None
+                }
+            };
+            if let Some(mint_limit) = mint_limit {
+                parameters.insert(format!("ibc_mint_limit.{}", token), mint_limit.raw_amount());
+            }
+            if let Some(throughput_limit) = throughput_limit {
+                parameters.insert(
+                    format!("ibc_throughput_limit.{}", token),
+                    throughput_limit.raw_amount(),
+                );
+            }
+        }
+
+        Ok(parameters)
+    }
+
     pub async fn read_storage_at_height(
         &self,
         key: &Key,
@@ -224,31 +777,268 @@ impl Rpc {
         }
     }
 
-    pub async fn query_native_token_supply(&self, token: &str) -> anyhow::Result<Supply> {
+    /// Read and decode a storage value at the end of the given block, None when it is not set
+    async fn read_value_at_height<T: BorshDeserialize>(
+        &self,
+        key: &Key,
+        height: Height,
+    ) -> anyhow::Result<Option<T>> {
+        let (value, _) =
+            rpc::query_storage_value_bytes(self.client.as_ref(), key, Some(height.into()), false)
+                .await
+                .context("Should be able to query storage at height")?;
+
+        value
+            .map(|value| T::try_from_slice(&value))
+            .transpose()
+            .context(format!("Should be able to decode storage value {}", key))
+    }
+
+    /// Read a protocol parameter at the end of the given block. Missing or undecodable values
+    /// are logged and skipped, so one bad key doesn't hide the others.
+    async fn read_parameter<T: BorshDeserialize>(
+        &self,
+        name: &str,
+        key: &Key,
+        height: Height,
+    ) -> Option<T> {
+        match self.read_value_at_height::<T>(key, height).await {
+            Ok(Some(value)) => Some(value),
+            Ok(None) => {
+                tracing::warn!("Missing parameter {} at block {}", name, height);
+                None
+            }
+            Err(e) => {
+                tracing::warn!("Missing parameter {} at block {}: {}", name, height, e);
+                None
+            }
+        }
+    }
+
+    /// Total supply of a token at the end of the given block
+    pub async fn query_total_supply_at_height(
+        &self,
+        token: &str,
+        height: Height,
+    ) -> anyhow::Result<Amount> {
+        use namada_sdk::token::storage_key;
+
         let address = NamadaAddress::from_str(token)
             .context("Should be able to convert string to address")?;
-        let total_supply_res = rpc::get_token_total_supply(self.client.as_ref(), &address).await;
-        let effect_supply_res = rpc::get_effective_native_supply(self.client.as_ref()).await;
+        let supply = self
+            .read_value_at_height::<Amount>(&storage_key::minted_balance_key(&address), height)
+            .await?;
+
+        Ok(supply.unwrap_or_default())
+    }
+
+    /// Native token inflation minted by the epoch switch at the given height. PoS and MASP
+    /// rewards are read from their records, while PGF inflation is recomputed the way the
+    /// protocol does, from the effective supply and the PGF and steward inflation rates.
+    pub async fn query_native_inflation(
+        &self,
+        native_token: &str,
+        height: Height,
+    ) -> anyhow::Result<Inflation> {
+        use namada_sdk::{
+            dec::Dec,
+            governance::pgf::storage::{keys as pgf_storage, steward::StewardDetail},
+            parameters::storage as params_storage,
+            proof_of_stake::storage_key as pos_storage,
+            token::storage_key,
+        };
 
-        let total_native_supply = total_supply_res
-            .context("Should be able to query total supply native token")
-            .map(|amount| amount.raw_amount().as_u64())?;
-        let effective_native_supply = effect_supply_res
-            .context("Should be able to query effective supply native token")
-            .map(|amount| amount.raw_amount().as_u64())?;
+        let prev_height = height.saturating_sub(1);
 
+        let pos = self
+            .read_value_at_height::<Amount>(&pos_storage::last_pos_inflation_amount_key(), height)
+            .await?
+            .unwrap_or_default();
+
+        let masp_rewards_key = storage_key::masp_total_rewards();
+        let masp_after = self
+            .read_value_at_height::<Amount>(&masp_rewards_key, height)
+            .await?
+            .unwrap_or_default();
+        let masp_before = self
+            .read_value_at_height::<Amount>(&masp_rewards_key, prev_height)
+            .await?
+            .unwrap_or_default();
+        let masp = masp_after.checked_sub(masp_before).unwrap_or_default();
+
+        // PGF inflation is computed on the effective supply, i.e. without the PGF balance, once
+        // PoS and MASP rewards are minted
+        let effective_supply = self
+            .query_effective_supply_at_height(native_token, prev_height)
+            .await?
+            .checked_add(pos)
+            .and_then(|supply| supply.checked_add(masp))
+            .unwrap_or_default();
+
+        let epochs_per_year = self
+            .read_value_at_height::<u64>(&params_storage::get_epochs_per_year_key(), prev_height)
+            .await?
+            .context("Should be able to query epochs per year")?;
+        let pgf_rate = self
+            .read_value_at_height::<Dec>(&pgf_storage::get_pgf_inflation_rate_key(), prev_height)
+            .await?
+            .unwrap_or_default();
+        let stewards_rate = self
+            .read_value_at_height::<Dec>(
+                &pgf_storage::get_steward_inflation_rate_key(),
+                prev_height,
+            )
+            .await?
+            .unwrap_or_default();
+
+        let per_epoch = |rate: Dec| {
+            effective_supply
+                .mul_floor(rate)
+                .map(|amount| amount.checked_div_u64(epochs_per_year).unwrap_or_default())
+                .context("Should be able to compute PGF inflation")
+        };
+        let mut pgf = per_epoch(pgf_rate)?;
+        let stewards_inflation = per_epoch(stewards_rate)?;
+
+        // Stewards as they were when the inflation was minted, read from their lazy map
+        let res = RPC
+            .shell()
+            .storage_prefix(
+                self.client.as_ref(),
+                None,
+                Some(BlockHeight(prev_height)),
+                false,
+                &pgf_storage::stewards_key_prefix(),
+            )
+            .await
+            .context("Should be able to query PGF stewards")?;
+        let stewards = res
+            .data
+            .into_iter()
+            .filter_map(|PrefixValue { value, .. }| StewardDetail::try_from_slice(&value).ok())
+            .collect::<Vec<_>>();
+        for share in stewards
+            .iter()
+            .flat_map(|steward| steward.reward_distribution.values())
+        {
+            let reward = stewards_inflation
+                .mul_floor(*share)
+                .context("Should be able to compute steward inflation")?;
+            pgf = pgf.checked_add(reward).unwrap_or_else(Amount::max);
+        }
+
+        Ok(Inflation { pos, masp, pgf })
+    }
+
+    /// Total bonded stake, and the PoS and PGF inflation rates
+    pub async fn query_economics(&self, epoch: Epoch, height: Height) -> anyhow::Result<Economics> {
+        use namada_sdk::{
+            dec::Dec, governance::pgf::storage::keys as pgf_storage,
+            parameters::storage as params_storage, proof_of_stake::storage_key as pos_storage,
+        };
+
+        let client = self.client.as_ref();
+        let to_f64 = |rate: Dec| rate.to_string().parse::<f64>().unwrap_or_default();
+        let amount_to_f64 = |amount: Amount| {
+            amount
+                .raw_amount()
+                .to_string()
+                .parse::<f64>()
+                .unwrap_or_default()
+        };
+
+        let native_token = self.query_native_token().await?;
+        let total_bonded = rpc::get_total_staked_tokens(client, NamadaEpoch(epoch))
+            .await
+            .context("Should be able to query total bonded stake")?;
+
+        // Same estimate as the protocol's staking rewards rate query, but at the given height
+        let effective_supply = self
+            .query_effective_supply_at_height(&native_token, height)
+            .await?;
+        let last_inflation = self
+            .read_value_at_height::<Amount>(&pos_storage::last_pos_inflation_amount_key(), height)
+            .await?
+            .unwrap_or_default();
+        let last_staked_ratio = self
+            .read_value_at_height::<Dec>(&pos_storage::last_staked_ratio_key(), height)
+            .await?
+            .unwrap_or_default();
+        let epochs_per_year = self
+            .read_value_at_height::<u64>(&params_storage::get_epochs_per_year_key(), height)
+            .await?
+            .context("Should be able to query epochs per year")?;
+        let pos_inflation_rate = if effective_supply.is_zero() {
+            0.0
+        } else {
+            amount_to_f64(last_inflation) * epochs_per_year as f64 / amount_to_f64(effective_supply)
+        };
+        let staking_rewards_rate = if last_staked_ratio.is_zero() {
+            0.0
+        } else {
+            pos_inflation_rate / to_f64(last_staked_ratio)
+        };
+
+        let pgf_inflation_rate = self
+            .read_value_at_height::<Dec>(&pgf_storage::get_pgf_inflation_rate_key(), height)
+            .await?
+            .context("Should be able to query PGF inflation rate")?;
+        let stewards_inflation_rate = self
+            .read_value_at_height::<Dec>(&pgf_storage::get_steward_inflation_rate_key(), height)
+            .await?
+            .context("Should be able to query steward inflation rate")?;
+
+        Ok(Economics {
+            native_token,
+            total_bonded,
+            pos_inflation_rate,
+            staking_rewards_rate,
+            pgf_inflation_rate: to_f64(pgf_inflation_rate),
+            stewards_inflation_rate: to_f64(stewards_inflation_rate),
+        })
+    }
+
+    /// Total native supply without the PGF balance at the end of the given block
+    async fn query_effective_supply_at_height(
+        &self,
+        native_token: &str,
+        height: Height,
+    ) -> anyhow::Result<Amount> {
+        use namada_sdk::{governance::pgf, token::storage_key};
+
+        let native_token = NamadaAddress::from_str(native_token)
+            .context("Should be able to convert string to address")?;
+        let total_supply = self
+            .read_value_at_height::<Amount>(&storage_key::minted_balance_key(&native_token), height)
+            .await?
+            .unwrap_or_default();
+        let pgf_balance = self
+            .read_value_at_height::<Amount>(
+                &storage_key::balance_key(&native_token, &pgf::ADDRESS),
+                height,
+            )
+            .await?
+            .unwrap_or_default();
+
+        Ok(total_supply.checked_sub(pgf_balance).unwrap_or_default())
+    }
+
+    /// Total and effective supply of the native token at the end of the given block
+    pub async fn query_native_token_supply(
+        &self,
+        token: &str,
+        height: Height,
+    ) -> anyhow::Result<Supply> {
         Ok(Supply {
-            total: total_native_supply,
-            effective: effective_native_supply,
+            total: self.query_total_supply_at_height(token, height).await?,
+            effective: self.query_effective_supply_at_height(token, height).await?,
             token: token.to_string(),
         })
     }
 
-    pub async fn query_token_supply(&self, token: &str) -> anyhow::Result<Supply> {
-        let address = NamadaAddress::from_str(token)
-            .context("Should be able to convert string to address")?;
-        let total_supply_res = rpc::get_token_total_supply(self.client.as_ref(), &address).await?;
-        let total_supply = total_supply_res.raw_amount().as_u64();
+    /// Total supply of a token at the end of the given block
+    pub async fn query_token_supply(&self, token: &str, height: Height) -> anyhow::Result<Supply> {
+        let total_supply = self.query_total_supply_at_height(token, height).await?;
 
         Ok(Supply {
             total: total_supply,
@@ -257,12 +1047,397 @@ impl Rpc {
         })
     }
 
-    pub async fn query_token_ibc_limit(&self, token: &str) -> anyhow::Result<u64> {
+    /// Enumerate the IBC clients, connections and transfer channels, and the tokens with a
+    /// non-zero supply
+    pub async fn discover(&self) -> anyhow::Result<Discovered> {
+        let (channels, clients) = self.query_ibc_topology().await?;
+        Ok(Discovered {
+            channels,
+            clients,
+            tokens: self.query_tokens_with_supply().await?,
+        })
+    }
+
+    /// Every open transfer channel with the connection and light client behind it, and the open
+    /// connections that no open transfer channel uses. Frozen or expired tendermint clients,
+    /// connections and channels that are not open (stale handshakes, closed channels) and clients
+    /// without an open connection are skipped, as monitoring them would only raise alerts.
+    pub async fn query_ibc_topology(
+        &self,
+    ) -> anyhow::Result<(Vec<DiscoveredChannel>, Vec<DiscoveredClient>)> {
+        use namada_sdk::ibc::{
+            core::{
+                channel::types::channel::ChannelEnd,
+                connection::types::ConnectionEnd,
+                host::types::identifiers::{ChannelId, ClientId, ConnectionId},
+            },
+            primitives::proto::Protobuf,
+            storage,
+        };
+
+        // ids are `channel-N`, `connection-N` and `07-tendermint-N`
+        let sequence = |id: &str, prefix: &str| {
+            id.strip_prefix(prefix)
+                .and_then(|sequence| sequence.parse::<u64>().ok())
+        };
+        let now = chrono::Utc::now().timestamp();
+
+        // Client ids share one counter across client types, so only some of them are tendermint
+        let client_counter = rpc::query_storage_value::<_, u64>(
+            self.client.as_ref(),
+            &storage::client_counter_key(),
+        )
+        .await
+        .context("Should be able to query the IBC client counter")?;
+        let mut clients = BTreeMap::new();
+        for client in 0..client_counter {
+            let client_id = ClientId::from_str(&format!("07-tendermint-{}", client))
+                .context("Should be a valid IBC client id")?;
+            let Some((client_state, client_expiry)) =
+                self.query_tendermint_client(&client_id, None).await?
+            else {
+                continue;
+            };
+            if client_state.is_frozen() || client_expiry.is_some_and(|expiry| expiry <= now) {
+                continue;
+            }
+            clients.insert(client, client_state.chain_id.to_string());
+        }
+
+        let connection_counter = rpc::query_storage_value::<_, u64>(
+            self.client.as_ref(),
+            &storage::connection_counter_key(),
+        )
+        .await
+        .context("Should be able to query the IBC connection counter")?;
+        let mut connections = BTreeMap::new();
+        for connection in 0..connection_counter {
+            let (value, _) = rpc::query_storage_value_bytes(
+                self.client.as_ref(),
+                &storage::connection_key(&ConnectionId::new(connection)),
+                None,
+                false,
+            )
+            .await
+            .context("Should be able to query IBC connection")?;
+            let Some(client) = value
+                .and_then(|value| ConnectionEnd::decode_vec(&value).ok())
+                .filter(|connection_end| connection_end.is_open())
+                .and_then(|connection_end| {
+                    sequence(connection_end.client_id().as_str(), "07-tendermint-")
+                })
+                .filter(|client| clients.contains_key(client))
+            else {
+                continue;
+            };
+            connections.insert(connection, client);
+        }
+
+        let prefix = storage::ibc_key("channelEnds/ports/transfer/channels")
+            .context("Should be able to build the IBC channels key")?;
+        let res = RPC
+            .shell()
+            .storage_prefix(self.client.as_ref(), None, None, false, &prefix)
+            .await
+            .context("Should be able to query IBC channels")?;
+
+        let mut channels = vec![];
+        for PrefixValue { key, value } in res.data {
+            let Some(DbKeySeg::StringSeg(channel_id)) = key.last() else {
+                continue;
+            };
+            let Some(channel) = ChannelId::from_str(channel_id)
+                .ok()
+                .and_then(|channel_id| sequence(channel_id.as_str(), "channel-"))
+            else {
+                continue;
+            };
+            let Some((connection, client)) = ChannelEnd::decode_vec(&value)
+                .ok()
+                .filter(|channel_end| channel_end.is_open())
+                .and_then(|channel_end| channel_end.connection_hops().first().cloned())
+                .and_then(|connection_id| sequence(connection_id.as_str(), "connection-"))
+                .and_then(|connection| Some((connection, *connections.get(&connection)?)))
+            else {
+                continue;
+            };
+
+            channels.push(DiscoveredChannel {
+                channel,
+                connection,
+                client,
+                counterparty_chain_id: clients[&client].clone(),
+            });
+        }
+
+        let mut standalone = BTreeMap::new();
+        for (connection, client) in connections {
+            if channels
+                .iter()
+                .any(|channel| channel.connection == connection)
+            {
+                continue;
+            }
+            standalone
+                .entry(client)
+                .or_insert_with(|| DiscoveredClient {
+                    connection,
+                    client,
+                    counterparty_chain_id: clients[&client].clone(),
+                });
+        }
+
+        Ok((channels, standalone.into_values().collect()))
+    }
+
+    /// State of a tendermint light client and the unix timestamp after which it can no longer be
+    /// updated, at the end of the given block or the latest one. None when the client does not
+    /// exist or is not a tendermint client.
+    async fn query_tendermint_client(
+        &self,
+        client_id: &namada_sdk::ibc::core::host::types::identifiers::ClientId,
+        height: Option<Height>,
+    ) -> anyhow::Result<Option<(TendermintClientState, Option<i64>)>> {
+        use namada_sdk::ibc::{
+            context::client::{AnyClientState, AnyConsensusState},
+            core::client::types::Height as IbcHeight,
+            primitives::proto::Any,
+            storage,
+        };
+        use prost::Message;
+
+        let read = |key: Key| async move {
+            let (value, _) = rpc::query_storage_value_bytes(
+                self.client.as_ref(),
+                &key,
+                height.map(BlockHeight),
+                false,
+            )
+            .await
+            .context("Should be able to query IBC client state")?;
+            anyhow::Ok(value)
+        };
+
+        let Some(client_state) = read(storage::client_state_key(client_id))
+            .await?
+            .and_then(|value| Any::decode(value.as_slice()).ok())
+            .and_then(|any| AnyClientState::try_from(any).ok())
+            .map(|AnyClientState::Tendermint(client_state)| client_state.inner().clone())
+        else {
+            return Ok(None);
+        };
+        let latest_height = IbcHeight::new(
+            client_state.latest_height.revision_number(),
+            client_state.latest_height.revision_height(),
+        )
+        .context("Should be a valid IBC height")?;
+        let client_expiry = read(storage::consensus_state_key(client_id, latest_height))
+            .await?
+            .and_then(|value| Any::decode(value.as_slice()).ok())
+            .and_then(|any| AnyConsensusState::try_from(any).ok())
+            .map(|AnyConsensusState::Tendermint(consensus_state)| {
+                consensus_state.inner().timestamp.unix_timestamp()
+                    + client_state.trusting_period.as_secs() as i64
+            });
+
+        Ok(Some((client_state, client_expiry)))
+    }
+
+    /// Denom traces of all IBC tokens, keyed by token address
+    pub async fn query_ibc_denom_traces(&self) -> anyhow::Result<BTreeMap<String, String>> {
+        use namada_sdk::{address::InternalAddress, ibc::storage};
+
+        let res = RPC
+            .shell()
+            .storage_prefix(
+                self.client.as_ref(),
+                None,
+                None,
+                false,
+                &storage::ibc_trace_key_prefix(None),
+            )
+            .await
+            .context("Should be able to query IBC denom traces")?;
+
+        // traces are stored once per receiver, so keep a single one per token
+        Ok(res
+            .data
+            .into_iter()
+            .filter_map(|PrefixValue { key, value }| {
+                let (_, hash) = storage::is_ibc_trace_key(&key)?;
+                let address =
+                    NamadaAddress::Internal(InternalAddress::IbcToken(hash.parse().ok()?));
+                let trace = String::try_from_slice(&value).ok()?;
+                Some((address.to_string(), trace))
+            })
+            .collect())
+    }
+
+    /// The native token and every IBC token with a non-zero total supply, the latter aliased by
+    /// their denom trace
+    pub async fn query_tokens_with_supply(&self) -> anyhow::Result<Vec<DiscoveredToken>> {
+        let native_token = self.query_native_token().await?;
+
+        let traces = self.query_ibc_denom_traces().await?;
+
+        let mut tokens = vec![DiscoveredToken {
+            alias: None,
+            address: native_token,
+        }];
+        for (address, trace) in traces {
+            let address = NamadaAddress::from_str(&address)
+                .context("Should be able to convert string to address")?;
+            let supply = rpc::get_token_total_supply(self.client.as_ref(), &address)
+                .await
+                .context("Should be able to query token total supply")?;
+            if supply.is_zero() {
+                continue;
+            }
+            tokens.push(DiscoveredToken {
+                alias: Some(trace),
+                address: address.to_string(),
+            });
+        }
+
+        Ok(tokens)
+    }
+
+    /// Read the state of an IBC channel and connection, and the expiry and last update time of
+    /// the light client behind them, at the end of the given block. Missing states are reported
+    /// as None. Without a channel only the connection and the light client are read.
+    pub async fn query_ibc_channel(
+        &self,
+        alias: &str,
+        channel: Option<u64>,
+        connection: u64,
+        client: u64,
+        height: Height,
+    ) -> anyhow::Result<IbcChannelStatus> {
+        use namada_sdk::ibc::{
+            core::{
+                channel::types::channel::ChannelEnd,
+                connection::types::ConnectionEnd,
+                host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId},
+            },
+            primitives::proto::Protobuf,
+            storage,
+        };
+        use namada_sdk::tendermint::Time;
+
+        let client_rpc = self.client.as_ref();
+        let read = |key: Key| async move {
+            let (value, _) =
+                rpc::query_storage_value_bytes(client_rpc, &key, Some(BlockHeight(height)), false)
+                    .await
+                    .context("Should be able to query IBC state")?;
+            anyhow::Ok(value)
+        };
+
+        let channel_id = channel.map(ChannelId::new);
+        let connection_id = ConnectionId::new(connection);
+        let client_id = ClientId::from_str(&format!("07-tendermint-{}", client))
+            .context("Should be a valid IBC client id")?;
+
+        let channel_end = match &channel_id {
+            Some(channel_id) => read(storage::channel_key(&PortId::transfer(), channel_id))
+                .await?
+                .and_then(|value| ChannelEnd::decode_vec(&value).ok()),
+            None => None,
+        };
+        let connection_end = read(storage::connection_key(&connection_id))
+            .await?
+            .and_then(|value| ConnectionEnd::decode_vec(&value).ok());
+
+        let client_expiry = self
+            .query_tendermint_client(&client_id, Some(height))
+            .await?
+            .and_then(|(_, client_expiry)| client_expiry);
+        let client_last_update = read(storage::client_update_timestamp_key(&client_id))
+            .await?
+            .and_then(|value| Time::decode_vec(&value).ok())
+            .map(|time| time.unix_timestamp());
+
+        Ok(IbcChannelStatus {
+            alias: alias.to_string(),
+            channel_id: channel_id.map(|channel_id| channel_id.to_string()),
+            connection_id: connection_id.to_string(),
+            client_id: client_id.to_string(),
+            channel_state: channel_end
+                .as_ref()
+                .map(|channel_end| channel_end.state().to_string()),
+            channel_open: channel_end.is_some_and(|channel_end| channel_end.is_open()),
+            connection_state: connection_end
+                .as_ref()
+                .map(|connection_end| connection_end.state().to_string()),
+            connection_open: connection_end.is_some_and(|connection_end| connection_end.is_open()),
+            client_expiry,
+            client_last_update,
+            pending_packets: BTreeMap::new(),
+        })
+    }
+
+    /// Sequences of the packets sent over a transfer channel whose commitment is still stored at
+    /// the end of the given block, i.e. that were neither acknowledged nor timed out
+    pub async fn query_ibc_packet_commitments(
+        &self,
+        channel: u64,
+        height: Height,
+    ) -> anyhow::Result<BTreeSet<u64>> {
+        use namada_sdk::ibc::{
+            core::host::types::{
+                identifiers::{ChannelId, PortId},
+                path::ChannelEndPath,
+            },
+            storage,
+        };
+
+        let path = ChannelEndPath::new(&PortId::transfer(), &ChannelId::new(channel));
+        let prefix = storage::ibc_key(path.commitments_path())
+            .context("Should be able to build the IBC commitments key")?;
+        let res = RPC
+            .shell()
+            .storage_prefix(
+                self.client.as_ref(),
+                None,
+                Some(BlockHeight(height)),
+                false,
+                &prefix,
+            )
+            .await
+            .context("Should be able to query IBC packet commitments")?;
+
+        Ok(res
+            .data
+            .into_iter()
+            .filter_map(|PrefixValue { key, .. }| storage::is_ibc_commitment_key(&key))
+            .map(|path| u64::from(path.sequence))
+            .collect())
+    }
+
+    /// Net amount of a token moved over IBC in the current epoch, as of the end of the given block
+    pub async fn query_token_ibc_throughput(
+        &self,
+        token: &str,
+        height: Height,
+    ) -> anyhow::Result<Amount> {
         let token = NamadaAddress::from_str(token)
             .context("Should be able to convert string to address")?;
-        let res = rpc::query_ibc_rate_limits(self.client.as_ref(), &token).await;
 
-        res.context("Should be able to query token IBC limit")
-            .map(|amount| amount.mint_limit.raw_amount().as_u64())
+        let deposit = self
+            .read_value_at_height::<Amount>(&namada_sdk::ibc::storage::deposit_key(&token), height)
+            .await?
+            .unwrap_or_default();
+        let withdraw = self
+            .read_value_at_height::<Amount>(&namada_sdk::ibc::storage::withdraw_key(&token), height)
+            .await?
+            .unwrap_or_default();
+        let throughput = if deposit < withdraw {
+            withdraw.checked_sub(deposit)
+        } else {
+            deposit.checked_sub(withdraw)
+        }
+        .unwrap_or_default();
+
+        Ok(throughput)
     }
 }
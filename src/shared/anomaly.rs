@@ -0,0 +1,339 @@
+use serde::Deserialize;
+
+use crate::state::{BlockState, State};
+
+use super::token;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+const SECONDS_PER_HOUR: i64 = 3_600;
+
+/// Per-block series derived from the state that can be checked for anomalies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Series {
+    /// Number of wrapper transactions in the block
+    Transactions,
+    /// Number of inner transactions that were not applied
+    FailedInners,
+    /// Gas used by all the transactions in the block
+    GasUsed,
+    /// Fees charged in the native token in the block, in denominated units
+    Fees,
+}
+
+impl Series {
+    pub fn value(&self, block_state: &BlockState) -> f64 {
+        let transactions = &block_state.block.transactions;
+        match self {
+            Series::Transactions => transactions.len() as f64,
+            Series::FailedInners => transactions
+                .iter()
+                .flat_map(|tx| tx.inners.iter())
+                .filter(|inner| !inner.was_applied)
+                .count() as f64,
+            Series::GasUsed => transactions.iter().map(|tx| tx.get_gas_used()).sum(),
+            Series::Fees => transactions
+                .iter()
+                .filter(|tx| tx.fee.gas_token == block_state.economics.native_token)
+                .filter_map(|tx| tx.fee.charged_fee())
+                .map(|fee| token::to_f64(&fee))
+                .sum(),
+        }
+    }
+
+    /// (timestamp, value) of every block in the state
+    pub fn points(&self, state: &State) -> Vec<(i64, f64)> {
+        state
+            .blocks
+            .iter()
+            .map(|block_state| (block_state.block.timestamp, self.value(block_state)))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Series {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Series::Transactions => write!(f, "transactions"),
+            Series::FailedInners => write!(f, "failed_inners"),
+            Series::GasUsed => write!(f, "gas_used"),
+            Series::Fees => write!(f, "fees"),
+        }
+    }
+}
+
+/// How the expected value of a series is estimated
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Baseline {
+    /// Mean and standard deviation of the history
+    Rolling,
+    /// Exponentially weighted mean and variance, with smoothing factor alpha
+    Ewma { alpha: f64 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anomaly {
+    pub value: f64,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub z_score: f64,
+}
+
+/// Running mean and variance of a series, updated one value at a time (Welford's algorithm)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Welford {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Welford {
+    pub fn add(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    pub fn variance(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        self.m2 / self.count as f64
+    }
+}
+
+/// History of a series per hour of the day. Unlike the blocks kept in the state, it covers
+/// every block seen since startup, so each hour keeps a baseline across days.
+#[derive(Debug, Clone, Default)]
+pub struct SeasonalHistory {
+    hours: [Welford; 24],
+    last_height: Option<u64>,
+}
+
+impl SeasonalHistory {
+    /// Baseline of the hour of the day of the given timestamp
+    pub fn hour(&self, timestamp: i64) -> &Welford {
+        &self.hours[hour_of_day(timestamp) as usize]
+    }
+
+    /// Add the value of a block, once per height
+    pub fn record(&mut self, height: u64, timestamp: i64, value: f64) {
+        if self.last_height.is_some_and(|last| height <= last) {
+            return;
+        }
+        self.last_height = Some(height);
+        self.hours[hour_of_day(timestamp) as usize].add(value);
+    }
+}
+
+/// Flags values that are more than `sigma` standard deviations off the baseline of their history
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnomalyDetector {
+    pub baseline: Baseline,
+    pub sigma: f64,
+    /// Compare with the running baseline of the same hour of the day, once it has enough samples
+    pub seasonal: bool,
+    /// History needed before any value is flagged
+    pub min_samples: usize,
+}
+
+impl AnomalyDetector {
+    /// Compare the last point of the series with the points before it, or with the baseline of
+    /// its hour of the day when the detector is seasonal and the hour has enough history
+    pub fn evaluate(&self, points: &[(i64, f64)], seasonal: Option<&Welford>) -> Option<Anomaly> {
+        let ((_, value), history) = points.split_last()?;
+
+        let (mean, variance) = match seasonal {
+            Some(hour) if self.seasonal && hour.count() >= self.min_samples as u64 => {
+                (hour.mean(), hour.variance())
+            }
+            _ => {
+                if history.is_empty() || history.len() < self.min_samples {
+                    return None;
+                }
+                let values = history.iter().map(|(_, value)| *value);
+                match self.baseline {
+                    Baseline::Rolling => rolling(values),
+                    Baseline::Ewma { alpha } => ewma(values, alpha),
+                }
+            }
+        };
+        let std_dev = variance.sqrt();
+        // A flat history makes any change infinitely unlikely; require some spread first
+        if std_dev <= f64::EPSILON {
+            return None;
+        }
+
+        let z_score = (value - mean) / std_dev;
+        (z_score.abs() > self.sigma).then_some(Anomaly {
+            value: *value,
+            mean,
+            std_dev,
+            z_score,
+        })
+    }
+}
+
+fn hour_of_day(timestamp: i64) -> i64 {
+    timestamp.rem_euclid(SECONDS_PER_DAY) / SECONDS_PER_HOUR
+}
+
+fn rolling(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    let values = values.collect::<Vec<_>>();
+    let count = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / count;
+    let variance = values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / count;
+    (mean, variance)
+}
+
+fn ewma(mut values: impl Iterator<Item = f64>, alpha: f64) -> (f64, f64) {
+    let Some(first) = values.next() else {
+        return (0.0, 0.0);
+    };
+    values.fold((first, 0.0), |(mean, variance), value| {
+        let delta = value - mean;
+        let mean = mean + alpha * delta;
+        let variance = (1.0 - alpha) * (variance + alpha * delta.powi(2));
+        (mean, variance)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detector(baseline: Baseline) -> AnomalyDetector {
+        AnomalyDetector {
+            baseline,
+            sigma: 3.0,
+            seasonal: false,
+            min_samples: 4,
+        }
+    }
+
+    fn points(values: &[f64]) -> Vec<(i64, f64)> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (index as i64 * 6, *value))
+            .collect()
+    }
+
+    #[test]
+    fn welford_matches_the_population_variance() {
+        let mut welford = Welford::default();
+        for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            welford.add(value);
+        }
+
+        assert_eq!(welford.count(), 8);
+        assert!((welford.mean() - 5.0).abs() < 1e-9);
+        assert!((welford.variance() - 4.0).abs() < 1e-9);
+        assert_eq!(Welford::default().variance(), 0.0);
+    }
+
+    #[test]
+    fn ewma_weights_recent_values() {
+        assert_eq!(ewma([].into_iter(), 0.5), (0.0, 0.0));
+        assert_eq!(ewma([4.0].into_iter(), 0.5), (4.0, 0.0));
+
+        let (mean, variance) = ewma([0.0, 4.0].into_iter(), 0.5);
+        assert!((mean - 2.0).abs() < 1e-9);
+        assert!((variance - 4.0).abs() < 1e-9);
+
+        let (mean, _) = ewma([0.0, 0.0, 0.0, 10.0].into_iter(), 0.9);
+        assert!((mean - 9.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn flags_outliers_of_the_rolling_baseline() {
+        let detector = detector(Baseline::Rolling);
+
+        let anomaly = detector
+            .evaluate(&points(&[9.0, 11.0, 9.0, 11.0, 30.0]), None)
+            .expect("Should flag the last value");
+        assert_eq!(anomaly.mean, 10.0);
+        assert_eq!(anomaly.std_dev, 1.0);
+        assert_eq!(anomaly.z_score, 20.0);
+
+        assert_eq!(
+            detector.evaluate(&points(&[9.0, 11.0, 9.0, 11.0, 12.0]), None),
+            None
+        );
+    }
+
+    #[test]
+    fn requires_history_and_spread() {
+        let detector = detector(Baseline::Rolling);
+
+        assert_eq!(detector.evaluate(&[], None), None);
+        assert_eq!(
+            detector.evaluate(&points(&[9.0, 11.0, 9.0, 30.0]), None),
+            None
+        );
+        assert_eq!(
+            detector.evaluate(&points(&[10.0, 10.0, 10.0, 10.0, 30.0]), None),
+            None
+        );
+    }
+
+    #[test]
+    fn flags_outliers_of_the_ewma_baseline() {
+        let detector = detector(Baseline::Ewma { alpha: 0.5 });
+
+        assert!(detector
+            .evaluate(&points(&[9.0, 11.0, 9.0, 11.0, 30.0]), None)
+            .is_some());
+        assert_eq!(
+            detector.evaluate(&points(&[9.0, 11.0, 9.0, 11.0, 10.0]), None),
+            None
+        );
+    }
+
+    #[test]
+    fn seasonal_baseline_replaces_the_history_once_filled() {
+        let detector = AnomalyDetector {
+            seasonal: true,
+            ..detector(Baseline::Rolling)
+        };
+        let series = points(&[9.0, 11.0, 9.0, 11.0, 30.0]);
+
+        let mut hour = Welford::default();
+        for value in [25.0, 35.0, 25.0, 35.0] {
+            hour.add(value);
+        }
+        assert_eq!(detector.evaluate(&series, Some(&hour)), None);
+
+        // Too few samples for the hour, so the rolling history is used
+        let mut sparse = Welford::default();
+        sparse.add(30.0);
+        assert!(detector.evaluate(&series, Some(&sparse)).is_some());
+    }
+
+    #[test]
+    fn seasonal_history_records_each_height_once_per_hour() {
+        let mut history = SeasonalHistory::default();
+        history.record(1, 0, 1.0);
+        history.record(1, 0, 100.0);
+        history.record(2, SECONDS_PER_DAY + 60, 3.0);
+        history.record(3, SECONDS_PER_HOUR, 5.0);
+
+        assert_eq!(history.hour(0).count(), 2);
+        assert_eq!(history.hour(0).mean(), 2.0);
+        assert_eq!(history.hour(SECONDS_PER_HOUR + 10).count(), 1);
+        assert_eq!(hour_of_day(-60), 23);
+    }
+}
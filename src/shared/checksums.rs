@@ -9,9 +9,19 @@ use namada_sdk::tx::{
 };
 use serde::{Deserialize, Serialize};
 
+/// Code name given to inner transactions whose code hash matches no known transaction
+pub const UNKNOWN_CODE_PREFIX: &str = "no_tx_code_name_with_id_";
+
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct Checksums(BiMap<String, String>);
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecksumChange {
+    pub name: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
 impl Checksums {
     pub fn get_name_by_id(&self, hash: &str) -> Option<String> {
         self.0.get_by_right(hash).map(|data| data.to_owned())
@@ -21,6 +31,36 @@ impl Checksums {
         self.0.get_by_left(name).map(|data| data.to_owned())
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.0.iter()
+    }
+
+    /// Transaction codes whose hash differs from the previous snapshot
+    pub fn changes(&self, prev: &Checksums) -> Vec<ChecksumChange> {
+        let mut names = self.0.left_values().collect::<Vec<_>>();
+        names.extend(prev.0.left_values());
+        names.sort();
+        names.dedup();
+
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let before = prev.get_id_by_name(name);
+                let after = self.get_id_by_name(name);
+                (before != after).then(|| ChecksumChange {
+                    name: name.clone(),
+                    before,
+                    after,
+                })
+            })
+            .collect()
+    }
+
+    /// The code hash of a transaction code name that matched no known transaction
+    pub fn unknown_code_id(code_name: &str) -> Option<&str> {
+        code_name.strip_prefix(UNKNOWN_CODE_PREFIX)
+    }
+
     pub fn add(&mut self, key: String, value: String) {
         let key = key.strip_suffix(".wasm").unwrap().to_owned();
         self.0.insert(key, value.to_lowercase());
@@ -54,3 +94,51 @@ impl Checksums {
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checksums(codes: &[(&str, &str)]) -> Checksums {
+        let mut checksums = Checksums::default();
+        for (name, hash) in codes {
+            checksums.add(format!("{}.wasm", name), hash.to_string());
+        }
+        checksums
+    }
+
+    #[test]
+    fn reports_upgraded_added_and_removed_codes() {
+        let prev = checksums(&[("tx_bond", "AA"), ("tx_unbond", "BB")]);
+        let next = checksums(&[("tx_bond", "CC"), ("tx_withdraw", "DD")]);
+
+        assert_eq!(
+            next.changes(&prev),
+            vec![
+                ChecksumChange {
+                    name: "tx_bond".to_string(),
+                    before: Some("aa".to_string()),
+                    after: Some("cc".to_string()),
+                },
+                ChecksumChange {
+                    name: "tx_unbond".to_string(),
+                    before: Some("bb".to_string()),
+                    after: None,
+                },
+                ChecksumChange {
+                    name: "tx_withdraw".to_string(),
+                    before: None,
+                    after: Some("dd".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn hashes_are_compared_case_insensitively() {
+        let prev = checksums(&[("tx_bond", "aa")]);
+        let next = checksums(&[("tx_bond", "AA")]);
+
+        assert!(next.changes(&prev).is_empty());
+    }
+}
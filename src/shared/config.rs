@@ -1,6 +1,10 @@
 use serde::Deserialize;
 
-use crate::shared::alert::Severity;
+use crate::shared::{
+    alert::Severity,
+    anomaly::{AnomalyDetector, Baseline, Series},
+    discovery::Discovered,
+};
 
 use super::block_explorer::BlockExplorer;
 
@@ -10,8 +14,26 @@ pub struct Config {
     pub chain: Chain,
     pub pos: Pos,
     pub tx: Tx,
+    /// `[[ibc]]` entries; the former `[[ibcs]]` key is still accepted
+    #[serde(rename = "ibc", alias = "ibcs", default)]
     pub ibcs: Vec<Ibc>,
+    /// `[[token]]` entries; the former `[[tokens]]` key is still accepted
+    #[serde(rename = "token", alias = "tokens", default)]
     pub tokens: Vec<TokenConfig>,
+    #[serde(default)]
+    pub discovery: DiscoveryConfig,
+    #[serde(rename = "validator", default)]
+    pub validators: Vec<ValidatorConfig>,
+    #[serde(rename = "watch", default)]
+    pub watches: Vec<WatchConfig>,
+    #[serde(rename = "velocity", default)]
+    pub velocities: Vec<VelocityConfig>,
+    #[serde(rename = "anomaly", default = "default_anomalies")]
+    pub anomalies: Vec<AnomalyConfig>,
+    #[serde(default)]
+    pub ibc_flow: IbcFlowConfig,
+    #[serde(default)]
+    pub supply: SupplyConfig,
     pub slack: Option<SlackAlertConfig>,
     pub telegram: Option<TelegramAlertConfig>,
 }
@@ -20,8 +42,215 @@ pub struct Config {
 pub struct TokenConfig {
     pub alias: String,
     pub token: String,
+    /// Maximum fee per transaction, in denominated units of the token
     pub fee_threshold: f64,
-    pub transfer_threshold: u64,
+    /// Maximum amount per transfer, in denominated units of the token
+    pub transfer_threshold: f64,
+    /// Alert when the supply reaches this fraction of the IBC mint limit
+    #[serde(default = "default_ibc_limit_threshold")]
+    pub ibc_mint_limit_threshold: f64,
+    /// Alert when this fraction of the per-epoch IBC throughput limit has been used
+    #[serde(default = "default_ibc_limit_threshold")]
+    pub ibc_throughput_threshold: f64,
+}
+
+fn default_ibc_limit_threshold() -> f64 {
+    0.8
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DiscoveryConfig {
+    /// Enumerate IBC channels and tokens with a non-zero supply at startup and monitor them
+    /// alongside the configured ones
+    #[serde(default)]
+    pub enabled: bool,
+    /// Maximum fee per transaction for discovered tokens, in denominated units of the token
+    #[serde(default = "default_discovery_fee_threshold")]
+    pub fee_threshold: f64,
+    /// Maximum amount per transfer for discovered tokens, in denominated units
+    #[serde(default = "default_discovery_transfer_threshold")]
+    pub transfer_threshold: f64,
+}
+
+impl Default for DiscoveryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            fee_threshold: default_discovery_fee_threshold(),
+            transfer_threshold: default_discovery_transfer_threshold(),
+        }
+    }
+}
+
+fn default_discovery_fee_threshold() -> f64 {
+    1.0
+}
+
+fn default_discovery_transfer_threshold() -> f64 {
+    1_000_000.0
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ValidatorConfig {
+    pub alias: String,
+    pub address: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WatchConfig {
+    pub address: String,
+    pub label: String,
+    /// Activity to alert on; every action when empty
+    #[serde(default)]
+    pub actions: Vec<WatchAction>,
+}
+
+impl WatchConfig {
+    pub fn watches(&self, action: WatchAction) -> bool {
+        self.actions.is_empty() || self.actions.contains(&action)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchAction {
+    TransferIn,
+    TransferOut,
+    Bond,
+    Unbond,
+    Withdraw,
+    UpdateAccount,
+    FeePayer,
+}
+
+impl std::fmt::Display for WatchAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WatchAction::TransferIn => write!(f, "transfer_in"),
+            WatchAction::TransferOut => write!(f, "transfer_out"),
+            WatchAction::Bond => write!(f, "bond"),
+            WatchAction::Unbond => write!(f, "unbond"),
+            WatchAction::Withdraw => write!(f, "withdraw"),
+            WatchAction::UpdateAccount => write!(f, "update_account"),
+            WatchAction::FeePayer => write!(f, "fee_payer"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct VelocityConfig {
+    pub token: String,
+    /// Maximum cumulative outflow per source address within the window, in denominated units
+    /// of the token
+    pub limit: f64,
+    /// Window length in blocks...
+    pub window_blocks: Option<u64>,
+    /// ...or in seconds, which takes precedence when both are set
+    pub window_seconds: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IbcFlowConfig {
+    /// Alert when the net outflow of a token over a channel exceeds this fraction of the
+    /// token's IBC mint limit, or of its total supply when it has no limit
+    #[serde(default = "default_net_outflow_ratio")]
+    pub net_outflow_ratio: f64,
+    /// Window over which the net outflow is computed, in seconds
+    #[serde(default = "default_net_outflow_window")]
+    pub window_seconds: u64,
+}
+
+impl Default for IbcFlowConfig {
+    fn default() -> Self {
+        Self {
+            net_outflow_ratio: default_net_outflow_ratio(),
+            window_seconds: default_net_outflow_window(),
+        }
+    }
+}
+
+fn default_net_outflow_ratio() -> f64 {
+    0.1
+}
+
+fn default_net_outflow_window() -> u64 {
+    60 * 60
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SupplyConfig {
+    /// Unexplained supply change tolerated at epoch boundaries, as a fraction of the PGF
+    /// inflation, which is recomputed rather than read from storage
+    #[serde(default = "default_pgf_tolerance")]
+    pub pgf_tolerance: f64,
+}
+
+impl Default for SupplyConfig {
+    fn default() -> Self {
+        Self {
+            pgf_tolerance: default_pgf_tolerance(),
+        }
+    }
+}
+
+fn default_pgf_tolerance() -> f64 {
+    0.01
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AnomalyConfig {
+    pub series: Series,
+    /// Alert when a value is this many standard deviations off the baseline
+    #[serde(default = "default_anomaly_sigma")]
+    pub sigma: f64,
+    /// Smoothing factor of an exponentially weighted baseline; a rolling mean is used when unset
+    pub ewma_alpha: Option<f64>,
+    /// Compare with a running baseline of the same hour of the day, kept since startup
+    #[serde(default)]
+    pub seasonal: bool,
+    /// Blocks of history needed before alerting
+    #[serde(default = "default_anomaly_min_samples")]
+    pub min_samples: usize,
+}
+
+impl AnomalyConfig {
+    pub fn detector(&self) -> AnomalyDetector {
+        AnomalyDetector {
+            baseline: match self.ewma_alpha {
+                Some(alpha) => Baseline::Ewma { alpha },
+                None => Baseline::Rolling,
+            },
+            sigma: self.sigma,
+            seasonal: self.seasonal,
+            min_samples: self.min_samples,
+        }
+    }
+}
+
+fn default_anomaly_sigma() -> f64 {
+    4.0
+}
+
+fn default_anomaly_min_samples() -> usize {
+    100
+}
+
+fn default_anomalies() -> Vec<AnomalyConfig> {
+    [
+        Series::Transactions,
+        Series::FailedInners,
+        Series::GasUsed,
+        Series::Fees,
+    ]
+    .into_iter()
+    .map(|series| AnomalyConfig {
+        series,
+        sigma: default_anomaly_sigma(),
+        ewma_alpha: None,
+        seasonal: true,
+        min_samples: default_anomaly_min_samples(),
+    })
+    .collect()
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -39,6 +268,41 @@ pub struct Pos {
     pub unbond_increase_threshold: f64,
     pub consensus_threshold: f64,
     pub threshold_missed_votes: f64,
+    /// Alert when a watched validator misses this many blocks...
+    #[serde(default = "default_missed_blocks_threshold")]
+    pub missed_blocks_threshold: u64,
+    /// ...within this many most recent blocks
+    #[serde(default = "default_missed_blocks_window")]
+    pub missed_blocks_window: u64,
+    /// Warn when a validator reaches this fraction of the missed votes that get it jailed
+    #[serde(default = "default_jail_warning_ratio")]
+    pub jail_warning_ratio: f64,
+    /// Alert on relative voting power changes of a validator between epochs above this value
+    #[serde(default = "default_voting_power_shift_threshold")]
+    pub voting_power_shift_threshold: f64,
+    /// Alert when a validator raises its commission rate by more than this, e.g. 0.05 for 5 points
+    #[serde(default = "default_commission_increase_threshold")]
+    pub commission_increase_threshold: f64,
+}
+
+fn default_missed_blocks_threshold() -> u64 {
+    10
+}
+
+fn default_missed_blocks_window() -> u64 {
+    100
+}
+
+fn default_jail_warning_ratio() -> f64 {
+    0.8
+}
+
+fn default_voting_power_shift_threshold() -> f64 {
+    0.1
+}
+
+fn default_commission_increase_threshold() -> f64 {
+    0.05
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -51,9 +315,32 @@ pub struct Tx {
 #[derive(Debug, Clone, Deserialize)]
 pub struct Ibc {
     pub alias: String,
-    pub channel: u64,
+    /// Transfer channel, omit it to only monitor the connection and its light client
+    #[serde(default)]
+    pub channel: Option<u64>,
     pub connection: u64,
     pub client_id: u64,
+    /// Alert when the client expires within this many seconds
+    #[serde(default = "default_ibc_expiry_horizon")]
+    pub expiry_horizon: u64,
+    /// Alert when the client has not been updated by a relayer for this many seconds
+    #[serde(default = "default_ibc_max_idle")]
+    pub max_idle: u64,
+    /// Alert when a sent packet stays unacknowledged for this many seconds
+    #[serde(default = "default_ibc_max_packet_age")]
+    pub max_packet_age: u64,
+}
+
+fn default_ibc_max_packet_age() -> u64 {
+    60 * 60
+}
+
+fn default_ibc_expiry_horizon() -> u64 {
+    60 * 60 * 24 * 2
+}
+
+fn default_ibc_max_idle() -> u64 {
+    60 * 60 * 6
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -72,6 +359,62 @@ pub struct TelegramAlertConfig {
 }
 
 impl Config {
+    /// Add the discovered channels, clients and tokens that are not configured explicitly, with
+    /// the default thresholds. Explicit entries always take precedence.
+    pub fn merge_discovered(&mut self, discovered: &Discovered) {
+        for channel in &discovered.channels {
+            if self
+                .ibcs
+                .iter()
+                .any(|ibc| ibc.channel == Some(channel.channel))
+            {
+                continue;
+            }
+            self.ibcs.push(Ibc {
+                alias: channel.alias(),
+                channel: Some(channel.channel),
+                connection: channel.connection,
+                client_id: channel.client,
+                expiry_horizon: default_ibc_expiry_horizon(),
+                max_idle: default_ibc_max_idle(),
+                max_packet_age: default_ibc_max_packet_age(),
+            });
+        }
+
+        for client in &discovered.clients {
+            if self.ibcs.iter().any(|ibc| ibc.client_id == client.client) {
+                continue;
+            }
+            self.ibcs.push(Ibc {
+                alias: client.alias(),
+                channel: None,
+                connection: client.connection,
+                client_id: client.client,
+                expiry_horizon: default_ibc_expiry_horizon(),
+                max_idle: default_ibc_max_idle(),
+                max_packet_age: default_ibc_max_packet_age(),
+            });
+        }
+
+        for token in &discovered.tokens {
+            if self
+                .tokens
+                .iter()
+                .any(|config| config.token == token.address)
+            {
+                continue;
+            }
+            self.tokens.push(TokenConfig {
+                alias: token.alias.clone().unwrap_or_else(|| token.address.clone()),
+                token: token.address.clone(),
+                fee_threshold: self.discovery.fee_threshold,
+                transfer_threshold: self.discovery.transfer_threshold,
+                ibc_mint_limit_threshold: default_ibc_limit_threshold(),
+                ibc_throughput_threshold: default_ibc_limit_threshold(),
+            });
+        }
+    }
+
     pub fn tokens(&self) -> Vec<(String, String)> {
         self.tokens
             .iter()
@@ -79,7 +422,7 @@ impl Config {
             .collect()
     }
 
-    pub fn tokens_thresholds(&self) -> Vec<(String, u64)> {
+    pub fn tokens_thresholds(&self) -> Vec<(String, f64)> {
         self.tokens
             .iter()
             .map(|token| (token.token.clone(), token.transfer_threshold))
@@ -93,7 +436,27 @@ impl Config {
             .collect()
     }
 
-    pub fn ibcs(&self) -> Vec<(String, u64, u64, u64)> {
+    pub fn tokens_ibc_thresholds(&self) -> Vec<(String, f64, f64)> {
+        self.tokens
+            .iter()
+            .map(|token| {
+                (
+                    token.token.clone(),
+                    token.ibc_mint_limit_threshold,
+                    token.ibc_throughput_threshold,
+                )
+            })
+            .collect()
+    }
+
+    pub fn validators(&self) -> Vec<(String, String)> {
+        self.validators
+            .iter()
+            .map(|validator| (validator.alias.clone(), validator.address.clone()))
+            .collect()
+    }
+
+    pub fn ibcs(&self) -> Vec<(String, Option<u64>, u64, u64)> {
         self.ibcs
             .iter()
             .map(|ibc| {
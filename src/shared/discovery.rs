@@ -0,0 +1,51 @@
+/// IBC channels, clients and tokens found on chain when discovery is enabled
+#[derive(Clone, Debug, Default)]
+pub struct Discovered {
+    pub channels: Vec<DiscoveredChannel>,
+    /// Light clients with an open connection that no open transfer channel uses
+    pub clients: Vec<DiscoveredClient>,
+    pub tokens: Vec<DiscoveredToken>,
+}
+
+/// An open transfer channel with the connection and tendermint light client behind it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiscoveredChannel {
+    pub channel: u64,
+    pub connection: u64,
+    pub client: u64,
+    /// Chain id tracked by the light client
+    pub counterparty_chain_id: String,
+}
+
+impl DiscoveredChannel {
+    pub fn alias(&self) -> String {
+        format!("{}/channel-{}", self.counterparty_chain_id, self.channel)
+    }
+}
+
+/// A tendermint light client and one of its open connections
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiscoveredClient {
+    pub connection: u64,
+    pub client: u64,
+    /// Chain id tracked by the light client
+    pub counterparty_chain_id: String,
+}
+
+impl DiscoveredClient {
+    pub fn alias(&self) -> String {
+        format!(
+            "{}/connection-{}",
+            self.counterparty_chain_id, self.connection
+        )
+    }
+}
+
+/// A token with a non-zero supply
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiscoveredToken {
+    /// The IBC denom trace for IBC tokens. None for the native token, whose alias is not stored
+    /// on chain.
+    pub alias: Option<String>,
+    pub address: String,
+}
@@ -0,0 +1,17 @@
+use namada_sdk::token::Amount;
+
+/// Staking and inflation figures of the native token, refreshed on new epochs
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Economics {
+    pub native_token: String,
+    /// Total bonded stake in the current epoch
+    pub total_bonded: Amount,
+    /// Annual PoS inflation rate, from the last minted PoS inflation
+    pub pos_inflation_rate: f64,
+    /// Estimated annual staking rewards rate
+    pub staking_rewards_rate: f64,
+    /// Annual PGF inflation rate, excluding stewards
+    pub pgf_inflation_rate: f64,
+    /// Annual steward inflation rate
+    pub stewards_inflation_rate: f64,
+}
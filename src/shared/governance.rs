@@ -0,0 +1,133 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use namada_sdk::{
+    governance::{
+        storage::proposal::StorageProposal,
+        utils::{ProposalResult, TallyResult},
+        ProposalType,
+    },
+    token::{Amount, DenominatedAmount},
+};
+
+use super::{namada::Epoch, token};
+
+/// Proposals the monitor keeps track of, up to their activation epoch
+#[derive(Clone, Debug, Default)]
+pub struct Governance {
+    /// Id the next submitted proposal will get
+    pub next_id: u64,
+    pub proposals: BTreeMap<u64, Proposal>,
+}
+
+impl Governance {
+    pub fn voting_proposals(&self, epoch: Epoch) -> impl Iterator<Item = &Proposal> {
+        self.proposals
+            .values()
+            .filter(move |proposal| proposal.is_voting(epoch))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Proposal {
+    pub id: u64,
+    pub author: String,
+    pub kind: String,
+    /// Hash of the wasm code executed on activation, if any
+    pub code_hash: Option<String>,
+    pub voting_start_epoch: Epoch,
+    pub voting_end_epoch: Epoch,
+    pub activation_epoch: Epoch,
+    pub tally: Option<Tally>,
+    /// Addresses that voted on the proposal
+    pub voters: BTreeSet<String>,
+}
+
+impl Proposal {
+    pub fn has_code(&self) -> bool {
+        self.code_hash.is_some()
+    }
+
+    /// Non-validators can vote up to and including the voting end epoch
+    pub fn is_voting(&self, epoch: Epoch) -> bool {
+        self.voting_start_epoch <= epoch && epoch <= self.voting_end_epoch
+    }
+
+    /// Voting is over but the proposal has not been activated yet
+    pub fn is_pending_activation(&self, epoch: Epoch) -> bool {
+        self.voting_end_epoch < epoch && epoch < self.activation_epoch
+    }
+
+    /// First epoch in which validators can no longer vote. Validators may only vote during the
+    /// first two thirds of the voting period, or its first epoch for short periods.
+    pub fn validator_voting_end_epoch(&self) -> Epoch {
+        if self.voting_end_epoch - self.voting_start_epoch <= 2 {
+            self.voting_start_epoch + 1
+        } else {
+            (self.voting_start_epoch + self.voting_end_epoch * 2).div_ceil(3)
+        }
+    }
+
+    pub fn can_validators_vote(&self, epoch: Epoch) -> bool {
+        self.voting_start_epoch <= epoch && epoch < self.validator_voting_end_epoch()
+    }
+
+    pub fn passed(&self) -> bool {
+        self.tally.as_ref().is_some_and(|tally| tally.passed)
+    }
+}
+
+impl From<StorageProposal> for Proposal {
+    fn from(proposal: StorageProposal) -> Self {
+        let (kind, code_hash) = match &proposal.r#type {
+            ProposalType::Default => ("default", None),
+            ProposalType::DefaultWithWasm(hash) => ("default_with_wasm", Some(hash.to_string())),
+            ProposalType::PGFSteward(_) => ("pgf_steward", None),
+            ProposalType::PGFPayment(_) => ("pgf_payment", None),
+        };
+
+        Self {
+            id: proposal.id,
+            author: proposal.author.to_string(),
+            kind: kind.to_string(),
+            code_hash,
+            voting_start_epoch: proposal.voting_start_epoch.0,
+            voting_end_epoch: proposal.voting_end_epoch.0,
+            activation_epoch: proposal.activation_epoch.0,
+            tally: None,
+            voters: BTreeSet::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tally {
+    /// Whether the proposal passes with the current votes
+    pub passed: bool,
+    pub total: Amount,
+    pub yay: Amount,
+    pub nay: Amount,
+    pub abstain: Amount,
+}
+
+impl Tally {
+    /// Share of the total voting power for the given amount of votes
+    pub fn ratio(&self, votes: Amount) -> f64 {
+        let total = token::to_f64(&DenominatedAmount::from(self.total));
+        if total == 0.0 {
+            return 0.0;
+        }
+        token::to_f64(&DenominatedAmount::from(votes)) / total
+    }
+}
+
+impl From<ProposalResult> for Tally {
+    fn from(result: ProposalResult) -> Self {
+        Self {
+            passed: matches!(result.result, TallyResult::Passed),
+            total: result.total_voting_power,
+            yay: result.total_yay_power,
+            nay: result.total_nay_power,
+            abstain: result.total_abstain_power,
+        }
+    }
+}
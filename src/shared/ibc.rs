@@ -0,0 +1,65 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Health of a configured IBC channel, its connection and its light client
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IbcChannelStatus {
+    pub alias: String,
+    /// None when only the connection and the light client are monitored
+    pub channel_id: Option<String>,
+    pub connection_id: String,
+    pub client_id: String,
+    /// State of the channel end, None when the channel does not exist or is not monitored
+    pub channel_state: Option<String>,
+    pub channel_open: bool,
+    /// State of the connection end, None when the connection does not exist
+    pub connection_state: Option<String>,
+    pub connection_open: bool,
+    /// Unix timestamp after which the client can no longer be updated
+    pub client_expiry: Option<i64>,
+    /// Unix timestamp of the last client update
+    pub client_last_update: Option<i64>,
+    /// Sequences of the packets sent over the channel that were neither acknowledged nor timed
+    /// out, with the timestamp of the block in which they were first seen pending
+    pub pending_packets: BTreeMap<u64, i64>,
+}
+
+impl IbcChannelStatus {
+    pub fn seconds_until_expiry(&self, now: i64) -> Option<i64> {
+        self.client_expiry.map(|expiry| expiry - now)
+    }
+
+    /// Track the packets with a pending commitment, keeping the time they were first seen from
+    /// the previous status. Packets already pending when monitoring started are dated from the
+    /// first block they were seen in.
+    pub fn track_pending_packets(
+        &mut self,
+        prev: Option<&IbcChannelStatus>,
+        commitments: BTreeSet<u64>,
+        timestamp: i64,
+    ) {
+        self.pending_packets = commitments
+            .into_iter()
+            .map(|sequence| {
+                let first_seen = prev
+                    .and_then(|prev| prev.pending_packets.get(&sequence))
+                    .copied()
+                    .unwrap_or(timestamp);
+                (sequence, first_seen)
+            })
+            .collect();
+    }
+
+    /// Pending packets older than the given age, as (sequence, seconds pending)
+    pub fn stuck_packets(&self, now: i64, max_age: i64) -> Vec<(u64, i64)> {
+        self.pending_packets
+            .iter()
+            .map(|(sequence, first_seen)| (*sequence, now - first_seen))
+            .filter(|(_, age)| *age > max_age)
+            .collect()
+    }
+
+    pub fn seconds_since_update(&self, now: i64) -> Option<i64> {
+        self.client_last_update
+            .map(|last_update| (now - last_update).max(0))
+    }
+}
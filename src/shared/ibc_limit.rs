@@ -0,0 +1,25 @@
+use namada_sdk::token::Amount;
+
+/// IBC rate limits of a token and the throughput used in the current epoch
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IbcRateLimit {
+    /// Maximum amount that can be minted over IBC
+    pub mint_limit: Amount,
+    /// Maximum net amount that can be moved over IBC within an epoch
+    pub throughput_limit: Amount,
+    /// Net amount moved over IBC in the current epoch, i.e. the difference between deposits
+    /// and withdrawals
+    pub throughput: Amount,
+}
+
+impl IbcRateLimit {
+    /// Share of the per-epoch throughput limit used so far
+    pub fn throughput_usage(&self) -> Option<f64> {
+        if self.throughput_limit.is_zero() {
+            return None;
+        }
+        let throughput = self.throughput.to_string().parse::<f64>().ok()?;
+        let limit = self.throughput_limit.to_string().parse::<f64>().ok()?;
+        Some(throughput / limit)
+    }
+}
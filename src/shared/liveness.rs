@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+/// PoS liveness parameters and the missed votes of each consensus validator
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Liveness {
+    /// Number of blocks over which missed votes are counted
+    pub window: u64,
+    /// Validators missing this many votes within the window get jailed
+    pub missed_votes_threshold: u64,
+    /// Missed votes within the window by validator address
+    pub missed_votes: HashMap<String, u64>,
+}
+
+impl Liveness {
+    pub fn missed_votes(&self, validator: &str) -> u64 {
+        self.missed_votes
+            .get(validator)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Missed votes of the validator once they reach `ratio` of the jailing threshold. None when
+    /// the validator is below it or the threshold is unknown.
+    pub fn nearing_jail(&self, validator: &str, ratio: f64) -> Option<u64> {
+        if self.missed_votes_threshold == 0 {
+            return None;
+        }
+        let missed_votes = self.missed_votes(validator);
+        (missed_votes as f64 >= self.missed_votes_threshold as f64 * ratio).then_some(missed_votes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn liveness(missed_votes_threshold: u64) -> Liveness {
+        Liveness {
+            window: 10_000,
+            missed_votes_threshold,
+            missed_votes: HashMap::from([
+                ("below".to_string(), 7_999),
+                ("at".to_string(), 8_000),
+                ("jailed".to_string(), 10_000),
+            ]),
+        }
+    }
+
+    #[test]
+    fn warns_from_the_ratio_of_the_threshold() {
+        let liveness = liveness(10_000);
+
+        assert_eq!(liveness.nearing_jail("below", 0.8), None);
+        assert_eq!(liveness.nearing_jail("at", 0.8), Some(8_000));
+        assert_eq!(liveness.nearing_jail("jailed", 0.8), Some(10_000));
+        assert_eq!(liveness.nearing_jail("unknown", 0.8), None);
+    }
+
+    #[test]
+    fn unknown_threshold_never_warns() {
+        let liveness = liveness(0);
+
+        assert_eq!(liveness.nearing_jail("jailed", 0.8), None);
+        assert_eq!(liveness.nearing_jail("unknown", 0.0), None);
+    }
+}
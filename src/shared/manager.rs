@@ -1,5 +1,9 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    sync::Arc,
+};
 
+use namada_sdk::{tendermint::evidence::Evidence, token::Amount};
 use tokio::sync::RwLock;
 
 use crate::{
@@ -7,6 +11,16 @@ use crate::{
     checks::CheckManager,
     metrics::MetricsExporter,
     rpc::Rpc,
+    shared::{
+        governance::Governance,
+        ibc_limit::IbcRateLimit,
+        namada::{Block, Epoch, InnerKind, SupplyEventKind, Validator},
+        parameters::Parameters,
+        slashes::SlashRecord,
+        supply::{Inflation, Supply, SupplyAudit},
+        token::{self, Token},
+        validator_metadata::ValidatorMetadataChange,
+    },
     state::{BlockState, State},
 };
 
@@ -16,6 +30,11 @@ pub struct Manager {
     pub alerts: AlertManager,
     pub rpc: Rpc,
     pub state: State,
+    /// Configured IBC channels as (alias, channel, connection, client)
+    pub ibcs: Vec<(String, Option<u64>, u64, u64)>,
+    /// Denom traces of the IBC tokens, keyed by token address. Refreshed when a new IBC token
+    /// shows up.
+    denom_traces: BTreeMap<String, String>,
 }
 
 impl Manager {
@@ -45,6 +64,8 @@ impl Manager {
             alerts,
             rpc,
             state: State::default(),
+            ibcs: config.get_config().ibcs(),
+            denom_traces: BTreeMap::new(),
         };
 
         for check in manager.checks.get_checks() {
@@ -80,29 +101,462 @@ impl Manager {
             .query_block_at_height(block_height, &checksums, epoch)
             .await?;
 
-        let validators = if epoch.eq(&last_epoch) {
-            self.state.last_block().validators.clone()
-        } else {
-            self.rpc.query_validators(epoch).await?
+        let (validators, consensus_addresses) = match self.state.blocks.last() {
+            Some(last_block) if epoch.eq(&last_epoch) => (
+                last_block.validators.clone(),
+                last_block.consensus_addresses.clone(),
+            ),
+            _ => {
+                let validators = self.rpc.query_validators(epoch).await?;
+                let consensus_addresses =
+                    Arc::new(BlockState::map_consensus_addresses(&validators));
+                (validators, consensus_addresses)
+            }
         };
         let (bonds, unbonds) = self.rpc.query_future_bonds_and_unbonds(epoch).await?;
+        let (liveness_window, missed_votes_threshold) = if epoch.eq(&last_epoch) {
+            let liveness = &self.state.last_block().liveness;
+            (liveness.window, liveness.missed_votes_threshold)
+        } else {
+            self.rpc.query_liveness_params().await?
+        };
+        let liveness = self
+            .rpc
+            .query_liveness_at_height(block_height, liveness_window, missed_votes_threshold)
+            .await?;
+
+        let mut seen_tokens = tokens
+            .iter()
+            .map(|(_, address)| address.clone())
+            .collect::<BTreeSet<_>>();
+        seen_tokens.extend(block.transactions.iter().map(|tx| tx.fee.gas_token.clone()));
+        seen_tokens.extend(block.get_all_transfers().into_iter().map(|t| t.token));
+        self.update_tokens(seen_tokens, &tokens).await;
+
+        let governance = match self.update_governance(&block, epoch, last_epoch).await {
+            Ok(governance) => governance,
+            Err(e) => {
+                tracing::error!("Error updating governance at block {}: {}", block_height, e);
+                if self.has_enough_blocks() {
+                    self.state.last_block().governance
+                } else {
+                    Governance::default()
+                }
+            }
+        };
+        let parameters = if epoch.eq(&last_epoch) {
+            self.state.last_block().parameters.clone()
+        } else {
+            let addresses = tokens
+                .iter()
+                .map(|(_, address)| address.clone())
+                .collect::<Vec<_>>();
+            self.rpc.query_parameters(&addresses, block_height).await?
+        };
+        let economics = if epoch.eq(&last_epoch) {
+            self.state.last_block().economics.clone()
+        } else {
+            self.rpc.query_economics(epoch, block_height).await?
+        };
+        let metadata_changes = self
+            .query_metadata_changes(&block, epoch, &parameters)
+            .await;
+        let slashes = match self.query_slashes(&block, epoch, &validators).await {
+            Ok(slashes) => slashes,
+            Err(e) => {
+                tracing::error!("Error querying slashes at block {}: {}", block_height, e);
+                vec![]
+            }
+        };
+        let prev_state = self.has_enough_blocks().then(|| self.state.last_block());
+        let mut ibc_channels = vec![];
+        for (alias, channel, connection, client) in &self.ibcs {
+            let mut status = match self
+                .rpc
+                .query_ibc_channel(alias, *channel, *connection, *client, block_height)
+                .await
+            {
+                Ok(status) => status,
+                Err(e) => {
+                    tracing::error!("Error querying IBC channel {}: {}", alias, e);
+                    continue;
+                }
+            };
+            let prev_status = prev_state
+                .as_ref()
+                .and_then(|state| state.ibc_channels.iter().find(|prev| &prev.alias == alias));
+            let Some(channel) = channel else {
+                ibc_channels.push(status);
+                continue;
+            };
+            match self
+                .rpc
+                .query_ibc_packet_commitments(*channel, block_height)
+                .await
+            {
+                Ok(commitments) => {
+                    status.track_pending_packets(prev_status, commitments, block.timestamp)
+                }
+                Err(e) => {
+                    tracing::error!("Error querying IBC packet commitments of {}: {}", alias, e);
+                    if let Some(prev_status) = prev_status {
+                        status.pending_packets = prev_status.pending_packets.clone();
+                    }
+                }
+            }
+            ibc_channels.push(status);
+        }
 
         let mut supplies = vec![];
-        let mut mint_limit = HashMap::new();
-        for (alias, address) in tokens {
-            let supply = if alias.contains("nam") {
-                self.rpc.query_native_token_supply(&address).await?
+        let mut ibc_limits = HashMap::new();
+        for (_, address) in tokens {
+            let supply = if address == economics.native_token {
+                self.rpc
+                    .query_native_token_supply(&address, block_height)
+                    .await
             } else {
-                self.rpc.query_token_supply(&address).await?
+                self.rpc.query_token_supply(&address, block_height).await
+            };
+            match supply {
+                Ok(supply) => supplies.push(supply),
+                Err(e) => tracing::error!("Error querying supply of {}: {}", address, e),
+            }
+            let (Some(mint_limit), Some(throughput_limit)) = (
+                parameters.amount(&format!("ibc_mint_limit.{}", address)),
+                parameters.amount(&format!("ibc_throughput_limit.{}", address)),
+            ) else {
+                tracing::warn!("Missing IBC rate limits of {}", address);
+                continue;
             };
-            let limit = self.rpc.query_token_ibc_limit(&address).await?;
-            mint_limit.insert(address.clone(), limit);
-            supplies.push(supply);
+            match self
+                .rpc
+                .query_token_ibc_throughput(&address, block_height)
+                .await
+            {
+                Ok(throughput) => {
+                    ibc_limits.insert(
+                        address.clone(),
+                        IbcRateLimit {
+                            mint_limit,
+                            throughput_limit,
+                            throughput,
+                        },
+                    );
+                }
+                Err(e) => tracing::error!("Error querying IBC throughput of {}: {}", address, e),
+            }
         }
 
-        let block_state = BlockState::new(block, bonds, unbonds, validators, supplies, mint_limit);
+        let supply_audits = self.query_supply_audits(&block, &supplies).await;
+
+        let block_state = BlockState::new(
+            block,
+            bonds,
+            unbonds,
+            validators,
+            consensus_addresses,
+            supplies,
+            ibc_limits,
+            liveness,
+            governance,
+            checksums,
+            parameters,
+            metadata_changes,
+            slashes,
+            ibc_channels,
+            supply_audits,
+            economics,
+        );
         self.state.add_block(block_state.clone());
 
         Ok(())
     }
+
+    /// Pick up newly submitted proposals, refresh the tallies of proposals that received votes
+    /// and, on new epochs, of every proposal open for voting
+    async fn update_governance(
+        &self,
+        block: &Block,
+        epoch: Epoch,
+        last_epoch: Epoch,
+    ) -> anyhow::Result<Governance> {
+        let mut governance = if self.has_enough_blocks() {
+            self.state.last_block().governance
+        } else {
+            Governance::default()
+        };
+        let new_epoch = epoch != last_epoch;
+
+        let next_id = self.rpc.query_proposal_counter(block.height).await?;
+        for id in governance.next_id..next_id {
+            if let Some(mut proposal) = self.rpc.query_proposal(id, block.height).await? {
+                if proposal.activation_epoch >= epoch {
+                    proposal.voters = self.rpc.query_proposal_voters(id, block.height).await?;
+                    governance.proposals.insert(id, proposal);
+                }
+            }
+        }
+        governance.next_id = next_id;
+
+        if new_epoch {
+            governance
+                .proposals
+                .retain(|_, proposal| proposal.activation_epoch >= epoch);
+        }
+
+        let votes = block
+            .transactions
+            .iter()
+            .filter(|tx| tx.is_successful())
+            .flat_map(|tx| tx.inners.iter())
+            .filter(|inner| inner.was_applied)
+            .filter_map(|inner| match &inner.kind {
+                InnerKind::ProposalVote(vote) => Some(vote),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let mut voted = BTreeSet::new();
+        for vote in votes {
+            if let Some(proposal) = governance.proposals.get_mut(&vote.id) {
+                proposal.voters.insert(vote.voter.to_string());
+            }
+            voted.insert(vote.id);
+        }
+
+        for (id, proposal) in governance.proposals.iter_mut() {
+            let refresh = voted.contains(id)
+                || proposal.tally.is_none()
+                || (new_epoch && proposal.voting_end_epoch + 1 >= epoch);
+            if refresh && proposal.voting_start_epoch <= epoch {
+                proposal.tally = self
+                    .rpc
+                    .query_proposal_tally(*id, epoch, block.height)
+                    .await?;
+            }
+        }
+
+        Ok(governance)
+    }
+
+    /// Read the metadata of every validator that changed its metadata or commission rate in
+    /// this block, before and after the change. Commission changes take effect at the pipeline
+    /// epoch, so the new rate is read there. Changes whose metadata can't be read are skipped.
+    async fn query_metadata_changes(
+        &self,
+        block: &Block,
+        epoch: Epoch,
+        parameters: &Parameters,
+    ) -> Vec<ValidatorMetadataChange> {
+        let pipeline_len = parameters
+            .get("pos.pipeline_len")
+            .and_then(|pipeline_len| pipeline_len.parse::<u64>().ok())
+            .unwrap_or_default();
+
+        let changes = block
+            .transactions
+            .iter()
+            .filter(|tx| tx.is_successful())
+            .flat_map(|tx| tx.inners.iter())
+            .filter(|inner| inner.was_applied)
+            .filter_map(|inner| match &inner.kind {
+                InnerKind::MetadataChange(change) => Some((inner.id.clone(), &change.validator)),
+                InnerKind::CommissionChange(change) => Some((inner.id.clone(), &change.validator)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut metadata_changes = vec![];
+        for (tx_id, validator) in changes {
+            let address = validator.to_string();
+            let before = self
+                .rpc
+                .query_validator_metadata_at_height(&address, block.height.saturating_sub(1), epoch)
+                .await;
+            let after = self
+                .rpc
+                .query_validator_metadata_at_height(&address, block.height, epoch + pipeline_len)
+                .await;
+            let (before, after) = match (before, after) {
+                (Ok(before), Ok(after)) => (before, after),
+                (Err(e), _) | (_, Err(e)) => {
+                    tracing::error!("Error querying metadata change of {}: {}", address, e);
+                    continue;
+                }
+            };
+            metadata_changes.push(ValidatorMetadataChange {
+                tx_id,
+                address,
+                before,
+                after,
+            });
+        }
+
+        metadata_changes
+    }
+
+    /// Correlate the evidence in this block with the slashes PoS enqueued for the offending
+    /// validators
+    async fn query_slashes(
+        &self,
+        block: &Block,
+        epoch: Epoch,
+        validators: &[Validator],
+    ) -> anyhow::Result<Vec<SlashRecord>> {
+        let consensus_addresses = block
+            .block
+            .evidence
+            .iter()
+            .flat_map(|evidence| match evidence {
+                Evidence::DuplicateVote(duplicate_vote) => {
+                    vec![duplicate_vote.vote_a.validator_address.to_string()]
+                }
+                Evidence::LightClientAttack(light_client_attack) => light_client_attack
+                    .byzantine_validators
+                    .iter()
+                    .map(|validator| validator.address.to_string())
+                    .collect(),
+            })
+            .collect::<BTreeSet<_>>();
+        if consensus_addresses.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let offenders = validators
+            .iter()
+            .filter(|validator| {
+                validator
+                    .consensus_address
+                    .as_ref()
+                    .is_some_and(|address| consensus_addresses.contains(address))
+            })
+            .map(|validator| validator.address.clone())
+            .collect::<BTreeSet<_>>();
+
+        self.rpc
+            .query_enqueued_slashes(&offenders, epoch, block.height)
+            .await
+    }
+
+    /// Compare the supply of each token at the end of this block with the previous block, the
+    /// mints and burns it emitted and, when the block starts an epoch, the native inflation.
+    /// Audits are skipped when the previous block is not in the state or a query fails.
+    async fn query_supply_audits(&self, block: &Block, supplies: &[Supply]) -> Vec<SupplyAudit> {
+        let Some(prev_state) = self
+            .state
+            .blocks
+            .last()
+            .filter(|prev_state| prev_state.block.height + 1 == block.height)
+        else {
+            return vec![];
+        };
+
+        let native_token = &prev_state.economics.native_token;
+        let inflation = if block.epoch != prev_state.block.epoch {
+            match self
+                .rpc
+                .query_native_inflation(native_token, block.height)
+                .await
+            {
+                Ok(inflation) => Some(inflation),
+                Err(e) => {
+                    tracing::error!("Error querying native inflation: {}", e);
+                    return supplies
+                        .iter()
+                        .filter(|supply| &supply.token != native_token)
+                        .filter_map(|supply| audit(block, prev_state, supply, None))
+                        .collect();
+                }
+            }
+        } else {
+            None
+        };
+
+        supplies
+            .iter()
+            .filter_map(|supply| {
+                let inflation = inflation.filter(|_| &supply.token == native_token);
+                audit(block, prev_state, supply, inflation)
+            })
+            .collect()
+    }
+
+    /// Cache the denomination, denom trace and configured alias of the tokens seen for the first
+    /// time. Tokens whose details can't be queried yet are left out, so they are retried on the
+    /// next block.
+    async fn update_tokens(&mut self, tokens: BTreeSet<String>, aliases: &[(String, String)]) {
+        let mut traces_refreshed = false;
+        for address in tokens {
+            if self.state.token(&address).is_some() {
+                continue;
+            }
+            let denom = if token::is_ibc_token(&address) {
+                if !self.denom_traces.contains_key(&address) && !traces_refreshed {
+                    traces_refreshed = true;
+                    match self.rpc.query_ibc_denom_traces().await {
+                        Ok(traces) => self.denom_traces = traces,
+                        Err(e) => tracing::warn!("Error querying IBC denom traces: {}", e),
+                    }
+                }
+                let Some(trace) = self.denom_traces.get(&address) else {
+                    tracing::warn!("No IBC denom trace found for token {}", address);
+                    continue;
+                };
+                Some(trace.clone())
+            } else {
+                None
+            };
+            let alias = aliases
+                .iter()
+                .find(|(_, configured)| configured == &address)
+                .map(|(alias, _)| alias.clone());
+            match self.rpc.query_token_denomination(&address).await {
+                Ok(Some(denomination)) => self.state.add_token(
+                    Token::new(address, denomination)
+                        .with_denom(denom)
+                        .with_alias(alias),
+                ),
+                Ok(None) => tracing::warn!("No denomination found for token {}", address),
+                Err(e) => tracing::warn!("Error querying denomination of {}: {}", address, e),
+            }
+        }
+    }
+}
+
+/// Audit of a token supply between the previous block and this one, None when the previous
+/// supply is unknown
+fn audit(
+    block: &Block,
+    prev_state: &BlockState,
+    supply: &Supply,
+    inflation: Option<Inflation>,
+) -> Option<SupplyAudit> {
+    let before = prev_state
+        .supplies
+        .iter()
+        .find(|prev| prev.token == supply.token)?
+        .total;
+    let (minted, burned) = block
+        .supply_events
+        .iter()
+        .filter(|event| event.token == supply.token)
+        .fold(
+            (Amount::zero(), Amount::zero()),
+            |(minted, burned), event| {
+                let add =
+                    |total: Amount| total.checked_add(event.amount).unwrap_or_else(Amount::max);
+                match event.kind {
+                    SupplyEventKind::Mint => (add(minted), burned),
+                    SupplyEventKind::Burn => (minted, add(burned)),
+                }
+            },
+        );
+
+    Some(SupplyAudit {
+        token: supply.token.clone(),
+        before,
+        after: supply.total,
+        minted,
+        burned,
+        inflation,
+    })
 }
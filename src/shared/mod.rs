@@ -1,8 +1,19 @@
 pub mod alert;
+pub mod anomaly;
 pub mod block_explorer;
 pub mod checksums;
 pub mod client;
 pub mod config;
+pub mod discovery;
+pub mod economics;
+pub mod governance;
+pub mod ibc;
+pub mod ibc_limit;
+pub mod liveness;
 pub mod manager;
 pub mod namada;
+pub mod parameters;
+pub mod slashes;
 pub mod supply;
+pub mod token;
+pub mod validator_metadata;
@@ -1,14 +1,17 @@
+use namada_sdk::account::UpdateAccount;
 use namada_sdk::borsh::BorshDeserialize;
 use namada_sdk::governance::{InitProposalData, VoteProposalData};
+use namada_sdk::ibc::apps::transfer::types::packet::PacketData;
+use namada_sdk::ibc::core::channel::types::msgs::PacketMsg;
+use namada_sdk::ibc::core::handler::types::msgs::MsgEnvelope;
 use namada_sdk::ibc::{self, IbcMessage};
 use namada_sdk::key::common::PublicKey;
 use namada_sdk::proof_of_stake::types::ValidatorState;
 use namada_sdk::tendermint::block::Block as TendermintBlock;
-use namada_sdk::uint::Uint;
 use std::collections::BTreeMap;
 use std::fmt::Display;
 
-use namada_sdk::token::Transfer as NamadaTransfer;
+use namada_sdk::token::{Amount, DenominatedAmount, Transfer as NamadaTransfer};
 use namada_sdk::tx::action::{Bond, ClaimRewards, Redelegation, Unbond, Withdraw};
 use namada_sdk::tx::data::pos::{BecomeValidator, CommissionChange, MetaDataChange};
 use namada_sdk::tx::data::{TxResult, TxType};
@@ -17,7 +20,9 @@ use std::str::FromStr;
 use tendermint_rpc::endpoint::block::Response;
 use tendermint_rpc::endpoint::block_results::Response as TendermintBlockResultResponse;
 
-use super::checksums::Checksums;
+use super::checksums::{Checksums, UNKNOWN_CODE_PREFIX};
+use super::token;
+use crate::error::DecodeError;
 
 pub type Height = u64;
 pub type Epoch = u64;
@@ -27,7 +32,9 @@ pub type Address = String;
 #[derive(Clone, Debug)]
 pub struct Validator {
     pub address: String,
-    pub voting_power: u64,
+    /// CometBFT address (uppercase hex) derived from the validator consensus key
+    pub consensus_address: Option<String>,
+    pub voting_power: Amount,
     pub state: ValidatorState,
 }
 
@@ -43,6 +50,75 @@ impl Validator {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValidatorSetChangeKind {
+    /// The validator was not part of the previous validator set
+    Joined,
+    EnteredConsensus,
+    LeftConsensus,
+    Jailed,
+    Unjailed,
+    BecameInactive,
+    Reactivated,
+    VotingPowerChanged {
+        before: Amount,
+        after: Amount,
+    },
+}
+
+impl ValidatorSetChangeKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ValidatorSetChangeKind::Joined => "joined",
+            ValidatorSetChangeKind::EnteredConsensus => "entered_consensus",
+            ValidatorSetChangeKind::LeftConsensus => "left_consensus",
+            ValidatorSetChangeKind::Jailed => "jailed",
+            ValidatorSetChangeKind::Unjailed => "unjailed",
+            ValidatorSetChangeKind::BecameInactive => "became_inactive",
+            ValidatorSetChangeKind::Reactivated => "reactivated",
+            ValidatorSetChangeKind::VotingPowerChanged { .. } => "voting_power_changed",
+        }
+    }
+}
+
+impl Display for ValidatorSetChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidatorSetChangeKind::VotingPowerChanged { before, after } => {
+                write!(
+                    f,
+                    "voting power changed from {} to {}",
+                    before.to_string_native(),
+                    after.to_string_native()
+                )
+            }
+            kind => write!(f, "{}", kind.label().replace('_', " ")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidatorSetChange {
+    pub address: String,
+    pub kind: ValidatorSetChangeKind,
+}
+
+impl ValidatorSetChange {
+    /// Relative voting power change, only defined for voting power changes
+    pub fn voting_power_shift(&self) -> Option<f64> {
+        match self.kind {
+            ValidatorSetChangeKind::VotingPowerChanged { before, after } => {
+                if before.is_zero() {
+                    Some(1.0)
+                } else {
+                    Some(token::ratio(after, before) - 1.0)
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Block {
     pub height: Height,
@@ -50,6 +126,9 @@ pub struct Block {
     pub timestamp: i64,
     pub transactions: Vec<Wrapper>,
     pub block: TendermintBlock,
+    pub decode_errors: Vec<DecodeError>,
+    /// Token mints and burns recorded in the block events
+    pub supply_events: Vec<SupplyEvent>,
 }
 
 #[derive(Clone, Debug)]
@@ -60,19 +139,10 @@ pub struct Wrapper {
     pub atomic: bool,
     pub total_sections: u64,
     pub status: TransactionExitStatus,
+    pub failure: Option<FailureReason>,
 }
 
 impl Wrapper {
-    pub fn compute_fee(&self) -> f64 {
-        let gas_limit = self.fee.gas.parse::<f64>().unwrap_or_default();
-        let amount_per_gas = self
-            .fee
-            .amount_per_gas_unit
-            .parse::<f64>()
-            .unwrap_or_default();
-        gas_limit * amount_per_gas
-    }
-
     pub fn get_gas_used(&self) -> f64 {
         self.fee.gas_used as f64
     }
@@ -84,13 +154,21 @@ impl Wrapper {
 
 #[derive(Debug, Clone)]
 pub struct Fee {
-    pub gas: String,
+    pub gas_limit: u64,
     pub gas_used: u64,
-    pub amount_per_gas_unit: String,
+    pub amount_per_gas_unit: DenominatedAmount,
     pub gas_payer: String,
     pub gas_token: String,
 }
 
+impl Fee {
+    /// The fee actually charged to the payer, i.e. the gas used times the gas price
+    pub fn charged_fee(&self) -> Option<DenominatedAmount> {
+        self.amount_per_gas_unit
+            .checked_mul(Amount::from_u64(self.gas_used).into())
+    }
+}
+
 #[derive(Clone, Debug)]
 #[allow(clippy::large_enum_variant)]
 pub enum InnerKind {
@@ -110,6 +188,7 @@ pub enum InnerKind {
     DeactivateValidator(Address),
     ReactivateValidator(Address),
     UnjailValidator(Address),
+    UpdateAccount(UpdateAccount),
     Unknown(String, Vec<u8>),
 }
 
@@ -132,6 +211,7 @@ impl Display for InnerKind {
             InnerKind::DeactivateValidator(_) => write!(f, "deactivate_validator"),
             InnerKind::ReactivateValidator(_) => write!(f, "reactivate_validator"),
             InnerKind::UnjailValidator(_) => write!(f, "unjail_validator"),
+            InnerKind::UpdateAccount(_) => write!(f, "update_account"),
             InnerKind::Unknown(code_name, ..) => write!(f, "unknown({})", code_name),
         }
     }
@@ -186,6 +266,9 @@ impl InnerKind {
             "tx_unjail_validator" => {
                 Address::try_from_slice(data).map_or_else(default, InnerKind::UnjailValidator)
             }
+            "tx_update_account" => {
+                UpdateAccount::try_from_slice(data).map_or_else(default, InnerKind::UpdateAccount)
+            }
             "tx_become_validator" => BecomeValidator::try_from_slice(data)
                 .map_or_else(default, |become_validator| {
                     InnerKind::BecomeValidator(become_validator)
@@ -213,6 +296,172 @@ pub struct Inner {
     pub kind: InnerKind,
 
     pub was_applied: bool,
+    pub failure: Option<FailureReason>,
+}
+
+impl Inner {
+    /// Fungible tokens sent or received over an IBC channel by this inner transaction. Amounts
+    /// come from the mints and burns the transaction emitted, so failed receptions are not
+    /// counted and refunds of timed out or failed transfers are credited back. Tokens escrowed
+    /// on Namada emit neither, so their packet amount is used instead.
+    pub fn ibc_flow(&self, supply_events: &[SupplyEvent]) -> Option<IbcFlow> {
+        let supply = |kind: SupplyEventKind, token: Option<&str>| {
+            supply_events
+                .iter()
+                .filter(|event| event.kind == kind)
+                .filter(|event| event.inner_tx.as_deref() == Some(self.id.to_uppercase().as_str()))
+                .filter(|event| token.is_none_or(|token| event.token == token))
+                .fold(None, |total: Option<(String, Amount)>, event| {
+                    let amount = match total {
+                        Some((_, total)) => total.checked_add(event.amount)?,
+                        None => event.amount,
+                    };
+                    Some((event.token.clone(), amount))
+                })
+        };
+
+        match &self.kind {
+            InnerKind::IbcMsgTransfer(IbcMessage::Transfer(msg_transfer)) => {
+                let message = &msg_transfer.message;
+                let coin = &message.packet_data.token;
+                let token = ibc::trace::convert_to_address(coin.denom.to_string())
+                    .ok()?
+                    .to_string();
+                // Vouchers sent back to their source are burnt, other tokens are escrowed
+                let amount = match supply(SupplyEventKind::Burn, Some(&token)) {
+                    Some((_, burnt)) => burnt,
+                    None => Amount::try_from(coin.amount).ok()?,
+                };
+                Some(IbcFlow {
+                    id: self.id.clone(),
+                    channel: message.chan_id_on_a.to_string(),
+                    token,
+                    amount,
+                    direction: IbcDirection::Outbound,
+                })
+            }
+            InnerKind::IbcMsgTransfer(IbcMessage::Envelope(envelope)) => match envelope.as_ref() {
+                MsgEnvelope::Packet(PacketMsg::Recv(msg)) => {
+                    let packet = &msg.packet;
+                    let packet_data = serde_json::from_slice::<PacketData>(&packet.data).ok()?;
+                    let denom = packet_data.token.denom.to_string();
+                    // Tokens coming back keep their original denom and are released from escrow,
+                    // foreign ones get prefixed with the receiving port and channel and minted
+                    let source_prefix = format!("{}/{}/", packet.port_id_on_a, packet.chan_id_on_a);
+                    let (trace, escrowed) = match denom.strip_prefix(&source_prefix) {
+                        Some(base_denom) => (base_denom.to_string(), true),
+                        None => (
+                            format!("{}/{}/{}", packet.port_id_on_b, packet.chan_id_on_b, denom),
+                            false,
+                        ),
+                    };
+                    let token = ibc::trace::convert_to_address(trace).ok()?.to_string();
+                    let amount = match supply(SupplyEventKind::Mint, Some(&token)) {
+                        Some((_, minted)) => minted,
+                        None if escrowed => Amount::try_from(packet_data.token.amount).ok()?,
+                        // Nothing was minted, so the reception failed
+                        None => return None,
+                    };
+                    Some(IbcFlow {
+                        id: self.id.clone(),
+                        channel: packet.chan_id_on_b.to_string(),
+                        token,
+                        amount,
+                        direction: IbcDirection::Inbound,
+                    })
+                }
+                MsgEnvelope::Packet(PacketMsg::Timeout(msg)) => self.ibc_refund(
+                    &msg.packet.chan_id_on_a,
+                    supply(SupplyEventKind::Mint, None),
+                ),
+                MsgEnvelope::Packet(PacketMsg::Ack(msg)) => self.ibc_refund(
+                    &msg.packet.chan_id_on_a,
+                    supply(SupplyEventKind::Mint, None),
+                ),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Vouchers minted back to the sender of a transfer that timed out or failed
+    fn ibc_refund(
+        &self,
+        channel: &impl Display,
+        minted: Option<(String, Amount)>,
+    ) -> Option<IbcFlow> {
+        let (token, amount) = minted?;
+        Some(IbcFlow {
+            id: self.id.clone(),
+            channel: channel.to_string(),
+            token,
+            amount,
+            direction: IbcDirection::Inbound,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IbcDirection {
+    Inbound,
+    Outbound,
+}
+
+impl Display for IbcDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IbcDirection::Inbound => write!(f, "inbound"),
+            IbcDirection::Outbound => write!(f, "outbound"),
+        }
+    }
+}
+
+/// Tokens moved over an IBC channel
+#[derive(Clone, Debug)]
+pub struct IbcFlow {
+    pub id: TxId,
+    /// Channel on the Namada side
+    pub channel: String,
+    pub token: String,
+    pub amount: Amount,
+    pub direction: IbcDirection,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FailureReason {
+    /// The wrapper was rejected, with the info reported by the node
+    WrapperRejected(String),
+    /// A validity predicate rejected the inner transaction
+    VpRejected { vp: Address, error: String },
+    /// The inner transaction failed while executing
+    ExecutionFailed(String),
+    /// The transaction failed but no reason was reported
+    Unknown,
+}
+
+impl FailureReason {
+    /// Bounded set of values, safe to use as a metric label
+    pub fn category(&self) -> &'static str {
+        match self {
+            FailureReason::WrapperRejected(_) => "wrapper_rejected",
+            FailureReason::VpRejected { .. } => "vp_rejected",
+            FailureReason::ExecutionFailed(_) => "execution_failed",
+            FailureReason::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for FailureReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FailureReason::WrapperRejected(info) => write!(f, "wrapper rejected: {}", info),
+            FailureReason::VpRejected { vp, error } => {
+                write!(f, "rejected by vp {}: {}", vp, error)
+            }
+            FailureReason::ExecutionFailed(error) => write!(f, "execution failed: {}", error),
+            FailureReason::Unknown => write!(f, "unknown"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -220,6 +469,7 @@ pub struct BlockResult {
     pub height: u64,
     pub begin_events: Vec<Event>,
     pub end_events: Vec<Event>,
+    pub decode_errors: Vec<DecodeError>,
 }
 
 #[derive(Debug, Clone)]
@@ -231,6 +481,8 @@ pub struct Event {
 #[derive(Debug, Clone)]
 pub enum EventKind {
     Applied,
+    Mint,
+    Burn,
     Unknown,
 }
 
@@ -238,6 +490,8 @@ impl From<&String> for EventKind {
     fn from(value: &String) -> Self {
         match value.as_str() {
             "tx/applied" => Self::Applied,
+            "token/mint" => Self::Mint,
+            "token/burn" => Self::Burn,
             _ => Self::Unknown,
         }
     }
@@ -246,6 +500,23 @@ impl From<&String> for EventKind {
 #[derive(Debug, Clone)]
 pub enum TxAttributesType {
     TxApplied(TxApplied),
+    Supply(SupplyEvent),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupplyEventKind {
+    Mint,
+    Burn,
+}
+
+/// A token mint or burn, as emitted by the IBC module
+#[derive(Debug, Clone)]
+pub struct SupplyEvent {
+    pub kind: SupplyEventKind,
+    pub token: String,
+    pub amount: Amount,
+    /// Hash (uppercase hex) of the inner transaction that emitted the event, if any
+    pub inner_tx: Option<TxId>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -278,6 +549,7 @@ impl From<&str> for TxEventStatusCode {
 pub struct BatchResults {
     pub batch_errors: BTreeMap<String, BTreeMap<String, String>>,
     pub batch_results: BTreeMap<String, bool>,
+    pub batch_failures: BTreeMap<String, FailureReason>,
 }
 
 impl From<TxResult<String>> for BatchResults {
@@ -286,7 +558,7 @@ impl From<TxResult<String>> for BatchResults {
             batch_results: value
                 .iter()
                 .fold(BTreeMap::default(), |mut acc, (tx_hash, result)| {
-                    let tx_id = tx_hash.to_string();
+                    let tx_id = tx_hash.to_string().to_uppercase();
                     let result = if let Ok(result) = result {
                         result.is_accepted()
                     } else {
@@ -312,17 +584,53 @@ impl From<TxResult<String>> for BatchResults {
                     acc.insert(tx_id, result);
                     acc
                 }),
+            batch_failures: value
+                .iter()
+                .fold(BTreeMap::default(), |mut acc, (tx_hash, result)| {
+                    let tx_id = tx_hash.to_string().to_uppercase();
+                    let failure = match result {
+                        Ok(result) if result.is_accepted() => None,
+                        Ok(result) => Some(
+                            result
+                                .vps_result
+                                .errors
+                                .first()
+                                .map(|(address, error)| FailureReason::VpRejected {
+                                    vp: address.to_string(),
+                                    error: error.clone(),
+                                })
+                                .or_else(|| {
+                                    result.vps_result.rejected_vps.first().map(|address| {
+                                        FailureReason::VpRejected {
+                                            vp: address.to_string(),
+                                            error: "rejected".to_string(),
+                                        }
+                                    })
+                                })
+                                .unwrap_or(FailureReason::Unknown),
+                        ),
+                        Err(error) => Some(FailureReason::ExecutionFailed(error.clone())),
+                    };
+                    if let Some(failure) = failure {
+                        acc.insert(tx_id, failure);
+                    }
+                    acc
+                }),
         }
     }
 }
 
 impl BatchResults {
     pub fn is_successful(&self, tx_id: &str) -> bool {
-        match self.batch_results.get(tx_id) {
+        match self.batch_results.get(&tx_id.to_uppercase()) {
             Some(result) => *result,
             None => false,
         }
     }
+
+    pub fn failure_reason(&self, tx_id: &str) -> Option<FailureReason> {
+        self.batch_failures.get(&tx_id.to_uppercase()).cloned()
+    }
 }
 
 impl Block {
@@ -333,100 +641,129 @@ impl Block {
         epoch: Epoch,
     ) -> Self {
         let block = response.block.clone();
-        Self {
-            block,
-            height: response.block.header.height.value(),
-            epoch,
-            timestamp: response.block.header.time.unix_timestamp(),
-            transactions: response
-                .block
-                .data
-                .into_iter()
-                .filter_map(|bytes| {
-                    let tx = Tx::try_from(bytes.as_ref()).ok()?;
-                    let wrapper_id = tx.header_hash();
-
-                    let wrapper_tx_id = wrapper_id.to_string();
-                    let wrapper_tx_status = block_results.is_wrapper_tx_applied(&wrapper_tx_id);
-                    let gas_used = block_results
-                        .gas_used(&wrapper_tx_id)
-                        .map(|gas| gas.parse::<u64>().unwrap_or_default())
-                        .unwrap_or_default();
-                    let atomic = tx.header().atomic;
-                    let total_sections = tx.sections.len() as u64;
-
-                    let fee = if let TxType::Wrapper(wrapper) = tx.header().tx_type {
-                        Fee {
-                            gas: Uint::from(wrapper.gas_limit).to_string(),
-                            gas_used,
-                            amount_per_gas_unit: wrapper
-                                .fee
-                                .amount_per_gas_unit
-                                .to_string_precise(),
-                            gas_payer: wrapper.fee_payer().to_string(),
-                            gas_token: wrapper.fee.token.to_string(),
-                        }
-                    } else {
+        let height = response.block.header.height.value();
+        let timestamp = response.block.header.time.unix_timestamp();
+
+        let mut decode_errors = block_results.decode_errors.clone();
+        let supply_events = block_results.supply_events();
+        let transactions = response
+            .block
+            .data
+            .into_iter()
+            .filter_map(|bytes| {
+                let tx = match Tx::try_from(bytes.as_ref()) {
+                    Ok(tx) => tx,
+                    Err(err) => {
+                        tracing::warn!("Skipping undecodable transaction: {}", err);
+                        decode_errors.push(DecodeError::InvalidTx(err.to_string()));
                         return None;
-                    };
+                    }
+                };
+                let wrapper_id = tx.header_hash();
 
-                    let inners = tx
-                        .header()
-                        .batch
-                        .into_iter()
-                        .map(|tx_commitment| {
-                            let tx_id = compute_inner_tx_hash(
-                                Some(&wrapper_id),
-                                Either::Right(&tx_commitment),
-                            )
-                            .to_string();
-
-                            let tx_code_id = tx
-                                .get_section(tx_commitment.code_sechash())
-                                .and_then(|s| s.code_sec())
-                                .map(|s| s.code.hash().0)
-                                .map(|bytes| {
-                                    String::from_utf8(subtle_encoding::hex::encode(bytes)).unwrap()
-                                });
+                let wrapper_tx_id = wrapper_id.to_string();
+                let wrapper_tx_status = block_results.is_wrapper_tx_applied(&wrapper_tx_id);
+                let gas_used = block_results
+                    .gas_used(&wrapper_tx_id)
+                    .map(|gas| gas.parse::<u64>().unwrap_or_default())
+                    .unwrap_or_default();
+                let atomic = tx.header().atomic;
+                let total_sections = tx.sections.len() as u64;
 
-                            let tx_data = tx.data(&tx_commitment).unwrap_or_default();
-                            let tx_size = tx_data.len();
-
-                            let tx_code_name = match tx_code_id {
-                                Some(id) => checksums
-                                    .get_name_by_id(&id)
-                                    .unwrap_or_else(|| format!("no_tx_code_name_with_id_{}", id)),
-                                None => "no_tx_id".into(),
-                            };
-
-                            let kind = InnerKind::from(&tx_code_name, &tx_data);
-                            let inner_tx_id = compute_inner_tx_hash(
-                                Some(&wrapper_id),
-                                Either::Right(&tx_commitment),
-                            )
-                            .to_string();
-                            let inner_tx_status =
-                                block_results.is_inner_tx_accepted(&wrapper_tx_id, &inner_tx_id);
-
-                            Inner {
-                                id: tx_id,
-                                size: tx_size,
-                                kind,
-                                was_applied: inner_tx_status.was_applied(),
-                            }
-                        })
-                        .collect();
-
-                    Some(Wrapper {
-                        id: wrapper_id.to_string(),
-                        inners,
-                        fee,
-                        atomic,
-                        total_sections,
-                        status: wrapper_tx_status,
+                let fee = if let TxType::Wrapper(wrapper) = tx.header().tx_type {
+                    Fee {
+                        gas_limit: u64::from(wrapper.gas_limit),
+                        gas_used,
+                        amount_per_gas_unit: wrapper.fee.amount_per_gas_unit,
+                        gas_payer: wrapper.fee_payer().to_string(),
+                        gas_token: wrapper.fee.token.to_string(),
+                    }
+                } else {
+                    return None;
+                };
+
+                let inners = tx
+                    .header()
+                    .batch
+                    .into_iter()
+                    .map(|tx_commitment| {
+                        let tx_id =
+                            compute_inner_tx_hash(Some(&wrapper_id), Either::Right(&tx_commitment))
+                                .to_string();
+
+                        let tx_code_id = tx
+                            .get_section(tx_commitment.code_sechash())
+                            .and_then(|s| s.code_sec())
+                            .map(|s| s.code.hash().0)
+                            .and_then(|bytes| {
+                                match String::from_utf8(subtle_encoding::hex::encode(bytes)) {
+                                    Ok(id) => Some(id),
+                                    Err(err) => {
+                                        decode_errors
+                                            .push(DecodeError::InvalidCodeHash(err.to_string()));
+                                        None
+                                    }
+                                }
+                            });
+
+                        let tx_data = tx.data(&tx_commitment).unwrap_or_default();
+                        let tx_size = tx_data.len();
+
+                        let tx_code_name = match tx_code_id {
+                            Some(id) => checksums
+                                .get_name_by_id(&id)
+                                .unwrap_or_else(|| format!("{}{}", UNKNOWN_CODE_PREFIX, id)),
+                            None => "no_tx_id".into(),
+                        };
+
+                        let kind = InnerKind::from(&tx_code_name, &tx_data);
+                        let inner_tx_id =
+                            compute_inner_tx_hash(Some(&wrapper_id), Either::Right(&tx_commitment))
+                                .to_string();
+                        let inner_tx_status =
+                            block_results.is_inner_tx_accepted(&wrapper_tx_id, &inner_tx_id);
+                        let failure = if inner_tx_status.was_applied() {
+                            None
+                        } else {
+                            Some(block_results.inner_tx_failure(&wrapper_tx_id, &inner_tx_id))
+                        };
+
+                        Inner {
+                            id: tx_id,
+                            size: tx_size,
+                            kind,
+                            was_applied: inner_tx_status.was_applied(),
+                            failure,
+                        }
                     })
+                    .collect();
+
+                let failure = if wrapper_tx_status.was_applied() {
+                    None
+                } else {
+                    Some(block_results.wrapper_tx_failure(&wrapper_tx_id))
+                };
+
+                Some(Wrapper {
+                    id: wrapper_id.to_string(),
+                    inners,
+                    fee,
+                    atomic,
+                    total_sections,
+                    status: wrapper_tx_status,
+                    failure,
                 })
-                .collect(),
+            })
+            .collect();
+
+        Self {
+            block,
+            height,
+            epoch,
+            timestamp,
+            transactions,
+            decode_errors,
+            supply_events,
         }
     }
 
@@ -493,15 +830,16 @@ impl Block {
             {
                 match &inner.kind {
                     InnerKind::Transfer(transfer) => {
-                        let mut groups: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+                        let mut groups: BTreeMap<String, Vec<Amount>> = BTreeMap::new();
                         for (a, b) in &transfer.targets {
                             groups
                                 .entry(a.token.to_string())
                                 .or_default()
-                                .push(b.amount().raw_amount().as_u64());
+                                .push(b.amount());
                         }
                         for (token, amounts) in groups {
-                            let total: u64 = amounts.iter().sum();
+                            let total =
+                                Amount::sum(amounts.into_iter()).unwrap_or_else(Amount::max);
                             transfers.push(Transfer {
                                 height: self.height,
                                 id: inner.id.clone(),
@@ -514,15 +852,16 @@ impl Block {
                     }
                     InnerKind::IbcMsgTransfer(IbcMessage::Transfer(msg_transfer)) => {
                         if let Some(transfer) = &msg_transfer.transfer {
-                            let mut groups: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+                            let mut groups: BTreeMap<String, Vec<Amount>> = BTreeMap::new();
                             for (a, b) in &transfer.targets {
                                 groups
                                     .entry(a.token.to_string())
                                     .or_default()
-                                    .push(b.amount().raw_amount().as_u64());
+                                    .push(b.amount());
                             }
                             for (token, amounts) in groups {
-                                let total: u64 = amounts.iter().sum();
+                                let total =
+                                    Amount::sum(amounts.into_iter()).unwrap_or_else(Amount::max);
                                 transfers.push(Transfer {
                                     height: self.height,
                                     id: inner.id.clone(),
@@ -558,7 +897,7 @@ pub struct Transfer {
     pub id: String,
     pub kind: TransferKind,
     pub token: String,
-    pub amount: u64,
+    pub amount: Amount,
     pub accepted: bool,
 }
 
@@ -568,6 +907,17 @@ pub struct IbcClient {
 }
 
 impl BlockResult {
+    pub fn supply_events(&self) -> Vec<SupplyEvent> {
+        self.begin_events
+            .iter()
+            .chain(self.end_events.iter())
+            .filter_map(|event| match &event.attributes {
+                Some(TxAttributesType::Supply(supply_event)) => Some(supply_event.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn is_wrapper_tx_applied(&self, tx_hash: &str) -> TransactionExitStatus {
         let tx_hash = tx_hash.to_uppercase();
         let exit_status = self
@@ -624,6 +974,49 @@ impl BlockResult {
             });
         exit_status.unwrap_or(TransactionExitStatus::Rejected)
     }
+
+    pub fn wrapper_tx_failure(&self, tx_hash: &str) -> FailureReason {
+        match self.tx_applied(tx_hash) {
+            Some(attributes) if !attributes.info.is_empty() => {
+                FailureReason::WrapperRejected(attributes.info.clone())
+            }
+            _ => FailureReason::Unknown,
+        }
+    }
+
+    pub fn inner_tx_failure(&self, wrapper_hash: &str, inner_hash: &str) -> FailureReason {
+        let Some(attributes) = self.tx_applied(wrapper_hash) else {
+            return FailureReason::Unknown;
+        };
+
+        match attributes.batch.failure_reason(inner_hash) {
+            Some(failure) => failure,
+            // the inner transaction never ran, so the wrapper must have been rejected
+            None if !attributes
+                .batch
+                .batch_results
+                .contains_key(&inner_hash.to_uppercase())
+                && !attributes.info.is_empty() =>
+            {
+                FailureReason::WrapperRejected(attributes.info.clone())
+            }
+            None => FailureReason::Unknown,
+        }
+    }
+
+    fn tx_applied(&self, tx_hash: &str) -> Option<&TxApplied> {
+        let tx_hash = tx_hash.to_uppercase();
+        self.end_events
+            .iter()
+            .filter_map(|event| {
+                if let Some(TxAttributesType::TxApplied(data)) = &event.attributes {
+                    Some(data)
+                } else {
+                    None
+                }
+            })
+            .find(|attributes| attributes.hash.eq(&tx_hash))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -664,94 +1057,256 @@ impl From<&TendermintBlockResultResponse> for BlockResult {
 
 impl From<TendermintBlockResultResponse> for BlockResult {
     fn from(value: TendermintBlockResultResponse) -> Self {
+        let mut decode_errors = vec![];
         let begin_events = value
             .begin_block_events
             .unwrap_or_default()
             .iter()
-            .map(|event| {
-                let kind = EventKind::from(&event.kind);
-                let raw_attributes =
-                    event
-                        .attributes
-                        .iter()
-                        .fold(BTreeMap::default(), |mut acc, attribute| {
-                            acc.insert(
-                                String::from(attribute.key_str().unwrap()),
-                                String::from(attribute.value_str().unwrap()),
-                            );
-                            acc
-                        });
-                let attributes = TxAttributesType::deserialize(&kind, &raw_attributes);
-                Event { kind, attributes }
+            .filter_map(|event| {
+                Event::decode(event)
+                    .map_err(|err| decode_errors.push(err))
+                    .ok()
             })
             .collect::<Vec<Event>>();
         let end_events = value
             .end_block_events
             .unwrap_or_default()
             .iter()
-            .map(|event| {
-                let kind = EventKind::from(&event.kind);
-                let raw_attributes =
-                    event
-                        .attributes
-                        .iter()
-                        .fold(BTreeMap::default(), |mut acc, attribute| {
-                            acc.insert(
-                                String::from(attribute.key_str().unwrap()),
-                                String::from(attribute.value_str().unwrap()),
-                            );
-                            acc
-                        });
-                let attributes = TxAttributesType::deserialize(&kind, &raw_attributes);
-                Event { kind, attributes }
+            .filter_map(|event| {
+                Event::decode(event)
+                    .map_err(|err| decode_errors.push(err))
+                    .ok()
             })
             .collect::<Vec<Event>>();
+
+        for err in &decode_errors {
+            tracing::warn!(
+                "Skipping undecodable event at height {}: {}",
+                value.height,
+                err
+            );
+        }
+
         Self {
             height: value.height.value(),
             begin_events,
             end_events,
+            decode_errors,
         }
     }
 }
 
+impl Event {
+    pub fn decode(event: &namada_sdk::tendermint::abci::Event) -> Result<Self, DecodeError> {
+        let kind = EventKind::from(&event.kind);
+        let raw_attributes =
+            event
+                .attributes
+                .iter()
+                .try_fold(BTreeMap::default(), |mut acc, attribute| {
+                    let key = attribute.key_str().map_err(|err| {
+                        DecodeError::InvalidAttribute(event.kind.clone(), err.to_string())
+                    })?;
+                    let value = attribute.value_str().map_err(|err| {
+                        DecodeError::InvalidAttribute(key.to_string(), err.to_string())
+                    })?;
+                    acc.insert(String::from(key), String::from(value));
+                    Ok::<_, DecodeError>(acc)
+                })?;
+        let attributes = TxAttributesType::deserialize(&kind, &raw_attributes)?;
+        Ok(Event { kind, attributes })
+    }
+}
+
 impl TxAttributesType {
     pub fn deserialize(
         event_kind: &EventKind,
         attributes: &BTreeMap<String, String>,
-    ) -> Option<Self> {
+    ) -> Result<Option<Self>, DecodeError> {
         match event_kind {
-            EventKind::Unknown => None,
-            EventKind::Applied => Some(Self::TxApplied(TxApplied {
-                code: attributes
-                    .get("code")
-                    .map(|code| TxEventStatusCode::from(code.as_str()))
-                    .unwrap()
-                    .to_owned(),
-                gas: attributes
-                    .get("gas_used")
-                    .map(|gas| u64::from_str(gas).unwrap())
-                    .unwrap()
-                    .to_owned(),
-                hash: attributes
-                    .get("hash")
-                    .map(|hash| hash.to_uppercase())
-                    .unwrap()
-                    .to_owned(),
-                height: attributes
-                    .get("height")
-                    .map(|height| u64::from_str(height).unwrap())
-                    .unwrap()
-                    .to_owned(),
-                batch: attributes
-                    .get("batch")
-                    .map(|batch_result| {
-                        let tx_result: TxResult<String> =
-                            serde_json::from_str(batch_result).unwrap();
-                        BatchResults::from(tx_result)
-                    })
-                    .unwrap(),
-                info: attributes.get("info").unwrap().to_owned(),
-            })),
+            EventKind::Unknown => Ok(None),
+            EventKind::Applied => {
+                let batch = Self::attribute(attributes, "batch")?;
+                let tx_result: TxResult<String> = serde_json::from_str(batch).map_err(|err| {
+                    DecodeError::InvalidAttribute("batch".to_string(), err.to_string())
+                })?;
+
+                Ok(Some(Self::TxApplied(TxApplied {
+                    code: TxEventStatusCode::from(Self::attribute(attributes, "code")?.as_str()),
+                    gas: Self::parse_attribute(attributes, "gas_used")?,
+                    hash: Self::attribute(attributes, "hash")?.to_uppercase(),
+                    height: Self::parse_attribute(attributes, "height")?,
+                    batch: BatchResults::from(tx_result),
+                    info: Self::attribute(attributes, "info")?.to_owned(),
+                })))
+            }
+            EventKind::Mint | EventKind::Burn => {
+                let amount = Self::attribute(attributes, "amount")?;
+                let amount = Amount::from_string_precise(amount).map_err(|err| {
+                    DecodeError::InvalidAttribute("amount".to_string(), err.to_string())
+                })?;
+
+                Ok(Some(Self::Supply(SupplyEvent {
+                    kind: if matches!(event_kind, EventKind::Mint) {
+                        SupplyEventKind::Mint
+                    } else {
+                        SupplyEventKind::Burn
+                    },
+                    token: Self::attribute(attributes, "token-address")?.to_owned(),
+                    amount,
+                    inner_tx: attributes
+                        .get("inner-tx-hash")
+                        .map(|hash| hash.to_uppercase()),
+                })))
+            }
         }
     }
+
+    fn attribute<'a>(
+        attributes: &'a BTreeMap<String, String>,
+        key: &str,
+    ) -> Result<&'a String, DecodeError> {
+        attributes
+            .get(key)
+            .ok_or_else(|| DecodeError::MissingAttribute(key.to_string()))
+    }
+
+    fn parse_attribute(
+        attributes: &BTreeMap<String, String>,
+        key: &str,
+    ) -> Result<u64, DecodeError> {
+        let value = Self::attribute(attributes, key)?;
+        u64::from_str(value)
+            .map_err(|err| DecodeError::InvalidAttribute(key.to_string(), err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_sdk::address::{Address as NamadaAddress, InternalAddress};
+    use namada_sdk::ibc::apps::transfer::types::PrefixedCoin;
+    use namada_sdk::ibc::core::channel::types::msgs::{MsgRecvPacket, MsgTimeout};
+    use namada_sdk::ibc::core::channel::types::packet::Packet;
+    use namada_sdk::ibc::core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
+    use namada_sdk::ibc::core::client::types::Height as IbcHeight;
+    use namada_sdk::ibc::core::commitment_types::commitment::CommitmentProofBytes;
+    use namada_sdk::ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+    use namada_sdk::ibc::primitives::Signer;
+
+    use super::*;
+
+    const INNER_ID: &str = "abcdef";
+
+    /// A transfer packet from channel-7 of the counterparty to channel-0 of Namada
+    fn packet(coin: &str) -> Packet {
+        let packet_data = PacketData {
+            token: PrefixedCoin::from_str(coin).unwrap(),
+            sender: Signer::from("osmo1sender".to_string()),
+            receiver: Signer::from("tnam1receiver".to_string()),
+            memo: String::new().into(),
+        };
+        Packet {
+            seq_on_a: Sequence::from(1),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(7),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(0),
+            data: serde_json::to_vec(&packet_data).unwrap(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: TimeoutTimestamp::Never,
+        }
+    }
+
+    fn inner(message: PacketMsg) -> Inner {
+        Inner {
+            id: INNER_ID.to_string(),
+            size: 0,
+            kind: InnerKind::IbcMsgTransfer(IbcMessage::Envelope(Box::new(MsgEnvelope::Packet(
+                message,
+            )))),
+            was_applied: true,
+            failure: None,
+        }
+    }
+
+    fn recv(coin: &str) -> Inner {
+        inner(PacketMsg::Recv(MsgRecvPacket {
+            packet: packet(coin),
+            proof_commitment_on_a: CommitmentProofBytes::try_from(vec![1]).unwrap(),
+            proof_height_on_a: IbcHeight::new(0, 1).unwrap(),
+            signer: Signer::from("relayer".to_string()),
+        }))
+    }
+
+    fn event(kind: SupplyEventKind, token: &str, amount: u64, inner_tx: &str) -> SupplyEvent {
+        SupplyEvent {
+            kind,
+            token: token.to_string(),
+            amount: Amount::from_u64(amount),
+            inner_tx: Some(inner_tx.to_uppercase()),
+        }
+    }
+
+    fn address(trace: &str) -> String {
+        ibc::trace::convert_to_address(trace).unwrap().to_string()
+    }
+
+    #[test]
+    fn received_vouchers_are_prefixed_with_the_receiving_channel() {
+        let token = address("transfer/channel-0/uosmo");
+        let events = [
+            event(SupplyEventKind::Mint, &token, 100, INNER_ID),
+            event(SupplyEventKind::Mint, &token, 900, "other"),
+        ];
+
+        let flow = recv("100uosmo").ibc_flow(&events).unwrap();
+        assert_eq!(flow.channel, "channel-0");
+        assert_eq!(flow.token, token);
+        assert_eq!(flow.amount, Amount::from_u64(100));
+        assert_eq!(flow.direction, IbcDirection::Inbound);
+    }
+
+    #[test]
+    fn failed_receptions_mint_nothing() {
+        let token = address("transfer/channel-0/uosmo");
+        let events = [event(SupplyEventKind::Mint, &token, 100, "other")];
+
+        assert!(recv("100uosmo").ibc_flow(&events).is_none());
+    }
+
+    #[test]
+    fn returning_tokens_lose_the_source_prefix() {
+        let native = NamadaAddress::Internal(InternalAddress::Governance).to_string();
+
+        let flow = recv(&format!("250transfer/channel-7/{}", native))
+            .ibc_flow(&[])
+            .unwrap();
+        assert_eq!(flow.token, native);
+        assert_eq!(flow.amount, Amount::from_u64(250));
+        assert_eq!(flow.direction, IbcDirection::Inbound);
+    }
+
+    #[test]
+    fn timeouts_credit_the_refunded_vouchers() {
+        let token = address("transfer/channel-0/uosmo");
+        let mut packet = packet("50transfer/channel-0/uosmo");
+        std::mem::swap(&mut packet.chan_id_on_a, &mut packet.chan_id_on_b);
+        let timeout = inner(PacketMsg::Timeout(MsgTimeout {
+            packet,
+            next_seq_recv_on_b: Sequence::from(1),
+            proof_unreceived_on_b: CommitmentProofBytes::try_from(vec![1]).unwrap(),
+            proof_height_on_b: IbcHeight::new(0, 1).unwrap(),
+            signer: Signer::from("relayer".to_string()),
+        }));
+
+        let flow = timeout
+            .ibc_flow(&[event(SupplyEventKind::Mint, &token, 50, INNER_ID)])
+            .unwrap();
+        assert_eq!(flow.channel, "channel-0");
+        assert_eq!(flow.token, token);
+        assert_eq!(flow.amount, Amount::from_u64(50));
+        assert_eq!(flow.direction, IbcDirection::Inbound);
+
+        assert!(timeout.ibc_flow(&[]).is_none());
+    }
 }
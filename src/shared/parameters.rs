@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+
+use namada_sdk::token::Amount;
+
+/// Snapshot of the protocol parameters, keyed by parameter name
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Parameters(BTreeMap<String, String>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParameterChange {
+    pub name: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl Parameters {
+    pub fn insert(&mut self, name: impl Into<String>, value: impl ToString) {
+        self.0.insert(name.into(), value.to_string());
+    }
+
+    pub fn get(&self, name: &str) -> Option<&String> {
+        self.0.get(name)
+    }
+
+    /// Parameter holding a raw token amount
+    pub fn amount(&self, name: &str) -> Option<Amount> {
+        self.get(name)
+            .and_then(|value| Amount::from_str(value, 0u8).ok())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Parameters with a numeric value
+    pub fn numeric(&self) -> impl Iterator<Item = (&String, f64)> {
+        self.0
+            .iter()
+            .filter_map(|(name, value)| value.parse::<f64>().ok().map(|value| (name, value)))
+    }
+
+    /// Parameters whose value differs from the previous snapshot
+    pub fn changes(&self, prev: &Parameters) -> Vec<ParameterChange> {
+        let mut names = self.0.keys().collect::<Vec<_>>();
+        names.extend(prev.0.keys());
+        names.sort();
+        names.dedup();
+
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let before = prev.get(name).cloned();
+                let after = self.get(name).cloned();
+                (before != after).then(|| ParameterChange {
+                    name: name.clone(),
+                    before,
+                    after,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameters(values: &[(&str, &str)]) -> Parameters {
+        let mut parameters = Parameters::default();
+        for (name, value) in values {
+            parameters.insert(*name, value);
+        }
+        parameters
+    }
+
+    #[test]
+    fn reports_changed_added_and_removed_parameters() {
+        let prev = parameters(&[("epochs_per_year", "365"), ("max_tx_bytes", "1024")]);
+        let next = parameters(&[("epochs_per_year", "730"), ("max_block_gas", "3000000")]);
+
+        assert_eq!(
+            next.changes(&prev),
+            vec![
+                ParameterChange {
+                    name: "epochs_per_year".to_string(),
+                    before: Some("365".to_string()),
+                    after: Some("730".to_string()),
+                },
+                ParameterChange {
+                    name: "max_block_gas".to_string(),
+                    before: None,
+                    after: Some("3000000".to_string()),
+                },
+                ParameterChange {
+                    name: "max_tx_bytes".to_string(),
+                    before: Some("1024".to_string()),
+                    after: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn unchanged_parameters_report_nothing() {
+        let prev = parameters(&[("epochs_per_year", "365")]);
+
+        assert!(prev.clone().changes(&prev).is_empty());
+        assert!(Parameters::default()
+            .changes(&Parameters::default())
+            .is_empty());
+    }
+}
@@ -0,0 +1,27 @@
+use namada_sdk::{proof_of_stake::types::SlashType, token::Amount};
+
+use super::namada::{Epoch, Height};
+
+/// A slash enqueued by PoS for a validator, waiting to be processed
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlashRecord {
+    pub validator: String,
+    pub kind: SlashType,
+    /// Minimum slash rate of the infraction; cubic slashing may raise it when processed
+    pub rate: f64,
+    pub infraction_epoch: Epoch,
+    pub block_height: Height,
+    /// Epoch at which the slash is processed and the stake is actually slashed
+    pub processing_epoch: Epoch,
+    /// Stake of the validator at the infraction epoch
+    pub stake: Amount,
+}
+
+impl SlashRecord {
+    pub fn label(&self) -> &'static str {
+        match self.kind {
+            SlashType::DuplicateVote => "duplicate_vote",
+            SlashType::LightClientAttack => "light_client_attack",
+        }
+    }
+}
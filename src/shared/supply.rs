@@ -1,6 +1,172 @@
+use std::cmp::Ordering;
+
+use namada_sdk::token::Amount;
+
+use super::token;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Supply {
-    pub total: u64,
-    pub effective: u64,
+    pub total: Amount,
+    pub effective: Amount,
     pub token: String,
 }
+
+/// Change of a token's total supply over a block, and what the chain's own records account for
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SupplyAudit {
+    pub token: String,
+    /// Total supply at the end of the previous block
+    pub before: Amount,
+    /// Total supply at the end of the block
+    pub after: Amount,
+    /// Mints and burns emitted as token events, i.e. IBC receives, sends and refunds
+    pub minted: Amount,
+    pub burned: Amount,
+    /// Inflation minted at an epoch boundary, native token only
+    pub inflation: Option<Inflation>,
+}
+
+/// Native token inflation minted at the start of an epoch
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Inflation {
+    /// PoS rewards, as recorded by PoS
+    pub pos: Amount,
+    /// Shielded rewards, from the MASP total rewards counter
+    pub masp: Amount,
+    /// PGF and steward inflation, recomputed from the PGF rates as the chain does not record it
+    pub pgf: Amount,
+}
+
+impl SupplyAudit {
+    /// Supply the records account for: the previous supply plus everything minted, minus burns
+    pub fn expected(&self) -> Amount {
+        let inflation = self
+            .inflation
+            .map(|inflation| [inflation.pos, inflation.masp, inflation.pgf])
+            .unwrap_or_default();
+        [self.minted]
+            .into_iter()
+            .chain(inflation)
+            .fold(self.before, |total, amount| {
+                total.checked_add(amount).unwrap_or_else(Amount::max)
+            })
+            .checked_sub(self.burned)
+            .unwrap_or_default()
+    }
+
+    /// The unexplained part of the change and whether the supply is above what was expected.
+    /// None when the records account for the whole change.
+    pub fn unexplained(&self) -> Option<(Amount, bool)> {
+        let expected = self.expected();
+        match self.after.cmp(&expected) {
+            Ordering::Equal => None,
+            Ordering::Greater => Some((self.after.checked_sub(expected)?, true)),
+            Ordering::Less => Some((expected.checked_sub(self.after)?, false)),
+        }
+    }
+
+    /// Recomputed amounts that can deviate slightly from what the chain minted
+    pub fn estimated(&self) -> Amount {
+        self.inflation
+            .map(|inflation| inflation.pgf)
+            .unwrap_or_default()
+    }
+
+    /// Like `unexplained`, but ignoring deviations of up to `tolerance` times the recomputed
+    /// amounts
+    pub fn unexplained_beyond(&self, tolerance: f64) -> Option<(Amount, bool)> {
+        let (unexplained, above) = self.unexplained()?;
+        let estimated = self.estimated();
+        let within = !estimated.is_zero() && token::ratio(unexplained, estimated) <= tolerance;
+        (!within).then_some((unexplained, above))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn audit(before: u64, after: u64, minted: u64, burned: u64) -> SupplyAudit {
+        SupplyAudit {
+            token: "token".to_string(),
+            before: Amount::from_u64(before),
+            after: Amount::from_u64(after),
+            minted: Amount::from_u64(minted),
+            burned: Amount::from_u64(burned),
+            inflation: None,
+        }
+    }
+
+    fn inflation(pos: u64, masp: u64, pgf: u64) -> Option<Inflation> {
+        Some(Inflation {
+            pos: Amount::from_u64(pos),
+            masp: Amount::from_u64(masp),
+            pgf: Amount::from_u64(pgf),
+        })
+    }
+
+    #[test]
+    fn exact_inflation_is_explained() {
+        let audit = SupplyAudit {
+            inflation: inflation(100, 20, 30),
+            ..audit(1_000, 1_150, 0, 0)
+        };
+
+        assert_eq!(audit.expected(), Amount::from_u64(1_150));
+        assert_eq!(audit.unexplained(), None);
+        assert_eq!(audit.unexplained_beyond(0.01), None);
+    }
+
+    #[test]
+    fn pgf_rounding_within_tolerance_is_ignored() {
+        let audit = SupplyAudit {
+            inflation: inflation(100, 0, 1_000),
+            ..audit(10_000, 11_105, 0, 0)
+        };
+
+        assert_eq!(audit.unexplained(), Some((Amount::from_u64(5), true)));
+        assert_eq!(audit.unexplained_beyond(0.01), None);
+        assert_eq!(
+            audit.unexplained_beyond(0.001),
+            Some((Amount::from_u64(5), true))
+        );
+    }
+
+    #[test]
+    fn unexplained_mint_is_reported() {
+        let audit = audit(1_000, 1_500, 0, 0);
+
+        assert_eq!(audit.expected(), Amount::from_u64(1_000));
+        // Nothing is recomputed, so no deviation is tolerated
+        assert_eq!(
+            audit.unexplained_beyond(0.5),
+            Some((Amount::from_u64(500), true))
+        );
+    }
+
+    #[test]
+    fn unexplained_burn_is_reported() {
+        let audit = audit(1_000, 900, 0, 0);
+
+        assert_eq!(
+            audit.unexplained_beyond(0.01),
+            Some((Amount::from_u64(100), false))
+        );
+    }
+
+    #[test]
+    fn ibc_mints_and_burns_are_explained() {
+        let audit = audit(1_000, 1_200, 500, 300);
+
+        assert_eq!(audit.expected(), Amount::from_u64(1_200));
+        assert_eq!(audit.unexplained_beyond(0.0), None);
+    }
+
+    #[test]
+    fn burns_above_the_supply_saturate() {
+        let audit = audit(100, 0, 0, 300);
+
+        assert_eq!(audit.expected(), Amount::zero());
+        assert_eq!(audit.unexplained(), None);
+    }
+}
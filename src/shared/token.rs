@@ -0,0 +1,96 @@
+use std::str::FromStr;
+
+use namada_sdk::{
+    address::{Address, InternalAddress},
+    token::{Amount, DenominatedAmount, Denomination},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Token {
+    pub address: String,
+    pub denomination: u8,
+    /// Denom trace of IBC tokens, e.g. `transfer/channel-1/uosmo`
+    pub denom: Option<String>,
+    /// Alias of the token in the `[[token]]` config
+    pub alias: Option<String>,
+}
+
+impl Token {
+    pub fn new(address: String, denomination: u8) -> Self {
+        Self {
+            address,
+            denomination,
+            denom: None,
+            alias: None,
+        }
+    }
+
+    pub fn with_denom(mut self, denom: Option<String>) -> Self {
+        self.denom = denom;
+        self
+    }
+
+    pub fn with_alias(mut self, alias: Option<String>) -> Self {
+        self.alias = alias;
+        self
+    }
+
+    /// Readable name of the token: its configured alias, else its denom trace, else its address
+    pub fn name(&self) -> &str {
+        self.alias
+            .as_deref()
+            .or(self.denom.as_deref())
+            .unwrap_or(&self.address)
+    }
+
+    pub fn denominate(&self, amount: Amount) -> DenominatedAmount {
+        DenominatedAmount::new(amount, Denomination(self.denomination))
+    }
+
+    /// Express a raw amount of this token in human units
+    pub fn to_f64(&self, amount: Amount) -> f64 {
+        to_f64(&self.denominate(amount))
+    }
+
+    /// Convert an amount expressed in human units to a raw amount of this token. Digits beyond
+    /// the token precision are rounded.
+    pub fn from_f64(&self, amount: f64) -> Amount {
+        let amount = amount.max(0.0);
+        let amount = match amount.to_string().split_once('.') {
+            Some((_, decimals)) if decimals.len() > self.denomination as usize => {
+                format!("{:.*}", self.denomination as usize, amount)
+            }
+            _ => amount.to_string(),
+        };
+        DenominatedAmount::from_str(&amount)
+            .and_then(|amount| amount.increase_precision(Denomination(self.denomination)))
+            .map(|amount| amount.amount())
+            .unwrap_or_else(|_| Amount::max())
+    }
+}
+
+/// Whether the address is an IBC token, whose name is its denom trace
+pub fn is_ibc_token(address: &str) -> bool {
+    matches!(
+        Address::from_str(address),
+        Ok(Address::Internal(InternalAddress::IbcToken(_)))
+    )
+}
+
+/// Express a raw amount of the native token, e.g. stake or voting power, in human units
+pub fn native_to_f64(amount: Amount) -> f64 {
+    amount.to_string_native().parse::<f64>().unwrap_or_default()
+}
+
+/// Ratio of two raw amounts of the same token
+pub fn ratio(numerator: Amount, denominator: Amount) -> f64 {
+    let denominator = to_f64(&DenominatedAmount::new(denominator, Denomination(0)));
+    if denominator == 0.0 {
+        return 0.0;
+    }
+    to_f64(&DenominatedAmount::new(numerator, Denomination(0))) / denominator
+}
+
+pub fn to_f64(amount: &DenominatedAmount) -> f64 {
+    amount.to_string().parse::<f64>().unwrap_or_default()
+}
@@ -0,0 +1,64 @@
+/// Validator metadata and commission rate as stored by PoS
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValidatorMetadata {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub description: Option<String>,
+    pub website: Option<String>,
+    pub discord_handle: Option<String>,
+    pub avatar: Option<String>,
+    pub commission_rate: Option<f64>,
+}
+
+impl ValidatorMetadata {
+    fn fields(&self) -> [(&'static str, Option<&String>); 6] {
+        [
+            ("name", self.name.as_ref()),
+            ("email", self.email.as_ref()),
+            ("description", self.description.as_ref()),
+            ("website", self.website.as_ref()),
+            ("discord_handle", self.discord_handle.as_ref()),
+            ("avatar", self.avatar.as_ref()),
+        ]
+    }
+}
+
+/// Metadata of a validator before and after a metadata or commission change transaction
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidatorMetadataChange {
+    pub tx_id: String,
+    pub address: String,
+    pub before: ValidatorMetadata,
+    pub after: ValidatorMetadata,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MetadataFieldChange {
+    pub field: &'static str,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl ValidatorMetadataChange {
+    /// Absolute commission rate increase, if the commission rate went up
+    pub fn commission_increase(&self) -> Option<f64> {
+        let before = self.before.commission_rate?;
+        let after = self.after.commission_rate?;
+        (after > before).then_some(after - before)
+    }
+
+    /// Metadata fields, other than the commission rate, whose value changed
+    pub fn field_changes(&self) -> Vec<MetadataFieldChange> {
+        self.before
+            .fields()
+            .into_iter()
+            .zip(self.after.fields())
+            .filter(|((_, before), (_, after))| before != after)
+            .map(|((field, before), (_, after))| MetadataFieldChange {
+                field,
+                before: before.cloned(),
+                after: after.cloned(),
+            })
+            .collect()
+    }
+}
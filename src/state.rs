@@ -1,37 +1,109 @@
-use std::collections::{BTreeMap, HashMap};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Display,
+    sync::Arc,
+};
 
 use anyhow::anyhow;
 use namada_sdk::{
-    address::Address, ibc::IbcMessage, proof_of_stake::types::ValidatorState,
-    token::Transfer as NamadaTransfer,
+    address::Address,
+    ibc::IbcMessage,
+    proof_of_stake::types::ValidatorState,
+    tendermint::block::CommitSig,
+    token::{Amount, DenominatedAmount, Transfer as NamadaTransfer},
 };
 
 use crate::shared::{
-    namada::{Block, Inner, InnerKind, Transfer, TransferKind, Validator, Wrapper},
-    supply::Supply,
+    checksums::Checksums,
+    economics::Economics,
+    governance::Governance,
+    ibc::IbcChannelStatus,
+    ibc_limit::IbcRateLimit,
+    liveness::Liveness,
+    namada::{
+        Block, IbcDirection, IbcFlow, Inner, InnerKind, Transfer, TransferKind, Validator,
+        ValidatorSetChange, ValidatorSetChangeKind, Wrapper,
+    },
+    parameters::Parameters,
+    slashes::SlashRecord,
+    supply::{Supply, SupplyAudit},
+    token::{self, Token},
+    validator_metadata::ValidatorMetadataChange,
 };
 
+/// Number of blocks kept in the state
+pub const MAX_BLOCKS: usize = 7200;
+
 pub(crate) const MASP_ADDRESS: Address =
     Address::Internal(namada_sdk::address::InternalAddress::Masp);
 
+/// Signing record of a validator over a window of blocks
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SigningStats {
+    pub signed: u64,
+    pub missed: u64,
+}
+
+impl SigningStats {
+    pub fn total(&self) -> u64 {
+        self.signed + self.missed
+    }
+
+    pub fn uptime(&self) -> f64 {
+        if self.total() == 0 {
+            return 1.0;
+        }
+        self.signed as f64 / self.total() as f64
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BlockState {
     pub block: Block,
-    pub bonds: u64,
-    pub unbonds: u64,
+    pub bonds: Amount,
+    pub unbonds: Amount,
     pub validators: Vec<Validator>,
     pub supplies: Vec<Supply>,
-    pub mint_limit: HashMap<String, u64>,
+    /// IBC rate limits per configured token
+    pub ibc_limits: HashMap<String, IbcRateLimit>,
+    /// CometBFT address (uppercase hex) to Namada validator address, built once per epoch
+    pub consensus_addresses: Arc<HashMap<String, String>>,
+    pub liveness: Liveness,
+    pub governance: Governance,
+    pub checksums: Checksums,
+    /// Protocol parameters, refreshed on new epochs
+    pub parameters: Parameters,
+    /// Validator metadata and commission changes applied in this block
+    pub metadata_changes: Vec<ValidatorMetadataChange>,
+    /// PoS slashes enqueued for the validators named in this block's evidence
+    pub slashes: Vec<SlashRecord>,
+    /// Health of the configured IBC channels
+    pub ibc_channels: Vec<IbcChannelStatus>,
+    /// Change of the total supply of each configured token over this block
+    pub supply_audits: Vec<SupplyAudit>,
+    /// Staking and inflation figures, refreshed on new epochs
+    pub economics: Economics,
 }
 
 impl BlockState {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         block: Block,
-        bonds: u64,
-        unbonds: u64,
+        bonds: Amount,
+        unbonds: Amount,
         validators: Vec<Validator>,
+        consensus_addresses: Arc<HashMap<String, String>>,
         supplies: Vec<Supply>,
-        mint_limit: HashMap<String, u64>,
+        ibc_limits: HashMap<String, IbcRateLimit>,
+        liveness: Liveness,
+        governance: Governance,
+        checksums: Checksums,
+        parameters: Parameters,
+        metadata_changes: Vec<ValidatorMetadataChange>,
+        slashes: Vec<SlashRecord>,
+        ibc_channels: Vec<IbcChannelStatus>,
+        supply_audits: Vec<SupplyAudit>,
+        economics: Economics,
     ) -> Self {
         Self {
             block,
@@ -39,8 +111,186 @@ impl BlockState {
             unbonds,
             validators,
             supplies,
-            mint_limit,
+            ibc_limits,
+            consensus_addresses,
+            liveness,
+            governance,
+            checksums,
+            parameters,
+            metadata_changes,
+            slashes,
+            ibc_channels,
+            supply_audits,
+            economics,
+        }
+    }
+
+    /// Whether each consensus validator signed the last commit of this block, as
+    /// (validator, signed)
+    pub fn signatures(&self) -> Vec<(String, bool)> {
+        let Some(last_commit) = &self.block.block.last_commit else {
+            return vec![];
+        };
+        let signers = last_commit
+            .signatures
+            .iter()
+            .filter_map(|signature| match signature {
+                CommitSig::BlockIdFlagCommit {
+                    validator_address, ..
+                } => Some(validator_address.to_string()),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+
+        self.validators
+            .iter()
+            .filter(|validator| matches!(validator.state, ValidatorState::Consensus))
+            .filter_map(|validator| {
+                let consensus_address = validator.consensus_address.as_ref()?;
+                Some((
+                    validator.address.clone(),
+                    signers.contains(consensus_address),
+                ))
+            })
+            .collect()
+    }
+
+    /// Charged fees of the transactions in this block as ((payer, token), fee)
+    pub fn charged_fees(&self) -> Vec<((String, String), DenominatedAmount)> {
+        self.block
+            .transactions
+            .iter()
+            .filter_map(|tx| {
+                tx.fee
+                    .charged_fee()
+                    .map(|fee| ((tx.fee.gas_payer.clone(), tx.fee.gas_token.clone()), fee))
+            })
+            .collect()
+    }
+
+    /// Map the CometBFT address of each validator to its Namada address
+    pub fn map_consensus_addresses(validators: &[Validator]) -> HashMap<String, String> {
+        validators
+            .iter()
+            .filter_map(|validator| {
+                validator
+                    .consensus_address
+                    .clone()
+                    .map(|consensus_address| (consensus_address, validator.address.clone()))
+            })
+            .collect()
+    }
+
+    /// Differences between the validator set of a previous block and this one. Validators are only
+    /// refreshed on new epochs, so the diff is empty within an epoch.
+    pub fn validator_set_changes(&self, prev: &BlockState) -> Vec<ValidatorSetChange> {
+        let previous = prev
+            .validators
+            .iter()
+            .map(|validator| (validator.address.as_str(), validator))
+            .collect::<HashMap<_, _>>();
+
+        let mut changes = Vec::new();
+        for validator in &self.validators {
+            let mut push = |kind| {
+                changes.push(ValidatorSetChange {
+                    address: validator.address.clone(),
+                    kind,
+                })
+            };
+            let state = &validator.state;
+
+            let Some(prev_validator) = previous.get(validator.address.as_str()) else {
+                push(ValidatorSetChangeKind::Joined);
+                if matches!(state, ValidatorState::Consensus) {
+                    push(ValidatorSetChangeKind::EnteredConsensus);
+                }
+                continue;
+            };
+            let prev_state = &prev_validator.state;
+
+            if prev_state != state {
+                match (prev_state, state) {
+                    (_, ValidatorState::Jailed) => push(ValidatorSetChangeKind::Jailed),
+                    (ValidatorState::Jailed, _) => push(ValidatorSetChangeKind::Unjailed),
+                    (_, ValidatorState::Inactive) => push(ValidatorSetChangeKind::BecameInactive),
+                    (ValidatorState::Inactive, _) => push(ValidatorSetChangeKind::Reactivated),
+                    _ => (),
+                }
+                match (prev_state, state) {
+                    (ValidatorState::Consensus, _) => push(ValidatorSetChangeKind::LeftConsensus),
+                    (_, ValidatorState::Consensus) => {
+                        push(ValidatorSetChangeKind::EnteredConsensus)
+                    }
+                    _ => (),
+                }
+            }
+
+            if prev_validator.voting_power != validator.voting_power {
+                push(ValidatorSetChangeKind::VotingPowerChanged {
+                    before: prev_validator.voting_power,
+                    after: validator.voting_power,
+                });
+            }
         }
+
+        changes
+    }
+
+    /// Transparent outflows as (source, token, amount) of the applied transfers and IBC
+    /// transfers in this block
+    pub fn outflows(&self) -> Vec<(String, String, Amount)> {
+        self.block
+            .transactions
+            .iter()
+            .filter(|tx| tx.is_successful())
+            .flat_map(|tx| tx.inners.iter())
+            .filter(|inner| inner.was_applied)
+            .filter_map(|inner| match &inner.kind {
+                InnerKind::Transfer(transfer) => Some(transfer),
+                InnerKind::IbcMsgTransfer(IbcMessage::Transfer(msg_transfer)) => {
+                    msg_transfer.transfer.as_ref()
+                }
+                _ => None,
+            })
+            .flat_map(|transfer| transfer.sources.iter())
+            .map(|(account, amount)| {
+                (
+                    account.owner.to_string(),
+                    account.token.to_string(),
+                    amount.amount(),
+                )
+            })
+            .collect()
+    }
+
+    /// Tokens moved over IBC channels by the applied inner transactions in this block
+    pub fn ibc_flows(&self) -> Vec<IbcFlow> {
+        self.block
+            .transactions
+            .iter()
+            .filter(|tx| tx.is_successful())
+            .flat_map(|tx| tx.inners.iter())
+            .filter(|inner| inner.was_applied)
+            .filter_map(|inner| inner.ibc_flow(&self.block.supply_events))
+            .collect()
+    }
+
+    pub fn validator_by_consensus_address(&self, consensus_address: &str) -> Option<&Validator> {
+        let address = self
+            .consensus_addresses
+            .get(&consensus_address.to_uppercase())?;
+        self.validators
+            .iter()
+            .find(|validator| &validator.address == address)
+    }
+
+    /// The Namada address for a CometBFT address, falling back to the hex address when unknown
+    pub fn validator_name(&self, consensus_address: &str) -> String {
+        self.consensus_addresses
+            .get(&consensus_address.to_uppercase())
+            .cloned()
+            .unwrap_or_else(|| consensus_address.to_string())
     }
 
     pub fn consensus_validators(&self) -> Vec<Validator> {
@@ -52,9 +302,48 @@ impl BlockState {
     }
 }
 
+/// Volume moved over an IBC channel
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IbcVolume {
+    pub inbound: Amount,
+    pub outbound: Amount,
+}
+
+impl IbcVolume {
+    /// Outbound minus inbound volume, zero when more came in than went out
+    pub fn net_outflow(&self) -> Amount {
+        self.outbound.checked_sub(self.inbound).unwrap_or_default()
+    }
+}
+
+/// Span of recent blocks to aggregate over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Window {
+    /// The last n blocks
+    Blocks(u64),
+    /// The blocks produced within n seconds of the last block
+    Seconds(u64),
+}
+
+impl Display for Window {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Window::Blocks(blocks) => write!(f, "{} blocks", blocks),
+            Window::Seconds(seconds) => write!(f, "{} seconds", seconds),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct State {
     pub blocks: Vec<BlockState>,
+    pub tokens: HashMap<String, Token>,
+    /// Charged fees per (payer, token) over the blocks kept in the state
+    charged_fees: HashMap<(String, String), DenominatedAmount>,
+    /// Signed and missed blocks per validator over the blocks kept in the state
+    signing_stats: HashMap<String, SigningStats>,
+    /// Inbound and outbound volume per (channel, token) over the blocks kept in the state
+    ibc_volumes: HashMap<(String, String), IbcVolume>,
     size: usize,
 }
 
@@ -62,7 +351,11 @@ impl Default for State {
     fn default() -> Self {
         Self {
             blocks: Default::default(),
-            size: 7200,
+            tokens: Default::default(),
+            charged_fees: Default::default(),
+            signing_stats: Default::default(),
+            ibc_volumes: Default::default(),
+            size: MAX_BLOCKS,
         }
     }
 }
@@ -70,11 +363,129 @@ impl Default for State {
 impl State {
     pub fn add_block(&mut self, block_state: BlockState) {
         if self.blocks.len() == self.size {
-            self.blocks.remove(0);
+            let evicted = self.blocks.remove(0);
+            self.untrack_fees(&evicted);
+            self.untrack_signatures(&evicted);
+            self.untrack_ibc_flows(&evicted);
         }
+        self.track_fees(&block_state);
+        self.track_signatures(&block_state);
+        self.track_ibc_flows(&block_state);
         self.blocks.push(block_state);
     }
 
+    fn track_fees(&mut self, block_state: &BlockState) {
+        for (key, fee) in block_state.charged_fees() {
+            let total = match self.charged_fees.get(&key) {
+                Some(total) => total.checked_add(fee).unwrap_or(*total),
+                None => fee,
+            };
+            self.charged_fees.insert(key, total);
+        }
+    }
+
+    fn untrack_fees(&mut self, block_state: &BlockState) {
+        for (key, fee) in block_state.charged_fees() {
+            let Some(total) = self.charged_fees.get(&key) else {
+                continue;
+            };
+            match total.checked_sub(fee) {
+                Some(total) if !total.is_zero() => {
+                    self.charged_fees.insert(key, total);
+                }
+                _ => {
+                    self.charged_fees.remove(&key);
+                }
+            }
+        }
+    }
+
+    fn track_signatures(&mut self, block_state: &BlockState) {
+        for (validator, signed) in block_state.signatures() {
+            let stats = self.signing_stats.entry(validator).or_default();
+            if signed {
+                stats.signed += 1;
+            } else {
+                stats.missed += 1;
+            }
+        }
+    }
+
+    fn untrack_signatures(&mut self, block_state: &BlockState) {
+        for (validator, signed) in block_state.signatures() {
+            let Some(stats) = self.signing_stats.get_mut(&validator) else {
+                continue;
+            };
+            if signed {
+                stats.signed = stats.signed.saturating_sub(1);
+            } else {
+                stats.missed = stats.missed.saturating_sub(1);
+            }
+            if stats.total() == 0 {
+                self.signing_stats.remove(&validator);
+            }
+        }
+    }
+
+    fn track_ibc_flows(&mut self, block_state: &BlockState) {
+        for flow in block_state.ibc_flows() {
+            let volume = self
+                .ibc_volumes
+                .entry((flow.channel, flow.token))
+                .or_default();
+            let amount = match flow.direction {
+                IbcDirection::Inbound => &mut volume.inbound,
+                IbcDirection::Outbound => &mut volume.outbound,
+            };
+            *amount = amount.checked_add(flow.amount).unwrap_or_else(Amount::max);
+        }
+    }
+
+    fn untrack_ibc_flows(&mut self, block_state: &BlockState) {
+        for flow in block_state.ibc_flows() {
+            let key = (flow.channel, flow.token);
+            let Some(volume) = self.ibc_volumes.get_mut(&key) else {
+                continue;
+            };
+            let amount = match flow.direction {
+                IbcDirection::Inbound => &mut volume.inbound,
+                IbcDirection::Outbound => &mut volume.outbound,
+            };
+            *amount = amount.checked_sub(flow.amount).unwrap_or_default();
+            if volume.inbound.is_zero() && volume.outbound.is_zero() {
+                self.ibc_volumes.remove(&key);
+            }
+        }
+    }
+
+    pub fn add_token(&mut self, token: Token) {
+        self.tokens.insert(token.address.clone(), token);
+    }
+
+    pub fn token(&self, address: &str) -> Option<&Token> {
+        self.tokens.get(address)
+    }
+
+    /// Readable name of a token for labels and alerts, falling back to its address
+    pub fn token_name<'a>(&'a self, address: &'a str) -> &'a str {
+        self.token(address).map(Token::name).unwrap_or(address)
+    }
+
+    /// Express a raw amount of the given token in human units. Tokens whose
+    /// denomination is unknown are treated as having no decimals, with a warning.
+    pub fn to_f64(&self, token: &str, amount: Amount) -> f64 {
+        match self.token(token) {
+            Some(token) => token.to_f64(amount),
+            None => {
+                tracing::warn!(
+                    "Unknown denomination for token {}, exporting its raw amount",
+                    token
+                );
+                Token::new(token.to_string(), 0).to_f64(amount)
+            }
+        }
+    }
+
     pub fn total_blocks(&self) -> usize {
         self.blocks.len()
     }
@@ -95,14 +506,15 @@ impl State {
         validators.reverse();
 
         let total_voting_power = self.total_voting_power();
-        let threshold_voting_power = (total_voting_power as f64 * fraction) as u64;
-        let mut accumulated_voting_power = 0;
+        let mut accumulated_voting_power = Amount::zero();
 
         for (index, validator) in validators.iter().enumerate() {
-            if accumulated_voting_power >= threshold_voting_power {
+            if token::ratio(accumulated_voting_power, total_voting_power) >= fraction {
                 return Ok(index as u64);
             }
-            accumulated_voting_power += validator.voting_power;
+            accumulated_voting_power = accumulated_voting_power
+                .checked_add(validator.voting_power)
+                .unwrap_or_else(Amount::max);
         }
         Err(anyhow!(
             "No validators can hold {} of the voting power",
@@ -110,14 +522,92 @@ impl State {
         ))
     }
 
-    pub fn total_voting_power(&self) -> u64 {
+    pub fn total_voting_power(&self) -> Amount {
         let block = self.last_block();
 
-        block
-            .validators
+        Amount::sum(
+            block
+                .validators
+                .iter()
+                .map(|validator| validator.voting_power),
+        )
+        .unwrap_or_else(Amount::max)
+    }
+
+    /// Blocks within the window, limited to the blocks kept in the state
+    pub fn blocks_within(&self, window: Window) -> &[BlockState] {
+        let start = match window {
+            Window::Blocks(blocks) => self.blocks.len().saturating_sub(blocks as usize),
+            Window::Seconds(seconds) => {
+                let Some(last) = self.blocks.last() else {
+                    return &[];
+                };
+                let since = last.block.timestamp - seconds as i64;
+                self.blocks
+                    .iter()
+                    .position(|block_state| block_state.block.timestamp > since)
+                    .unwrap_or(self.blocks.len())
+            }
+        };
+        &self.blocks[start..]
+    }
+
+    /// Inbound and outbound volume per (channel, token) over the blocks within the window
+    pub fn ibc_volumes(&self, window: Window) -> HashMap<(String, String), IbcVolume> {
+        let blocks = self.blocks_within(window);
+        if blocks.len() == self.blocks.len() {
+            return self.ibc_volumes.clone();
+        }
+
+        let mut volumes: HashMap<(String, String), IbcVolume> = HashMap::new();
+        for block_state in blocks {
+            for flow in block_state.ibc_flows() {
+                let volume = volumes.entry((flow.channel, flow.token)).or_default();
+                let amount = match flow.direction {
+                    IbcDirection::Inbound => &mut volume.inbound,
+                    IbcDirection::Outbound => &mut volume.outbound,
+                };
+                *amount = amount.checked_add(flow.amount).unwrap_or_else(Amount::max);
+            }
+        }
+        volumes
+    }
+
+    /// Total charged fees per (payer, token) over the whole state window, in denominated units
+    pub fn charged_fees_by_payer(&self) -> HashMap<(String, String), f64> {
+        self.charged_fees
             .iter()
-            .map(|validator| validator.voting_power)
-            .sum()
+            .map(|(key, fee)| (key.clone(), token::to_f64(fee)))
+            .collect()
+    }
+
+    /// Signed and missed blocks per consensus validator over the last `window` blocks,
+    /// built from the signatures in each block's last commit
+    pub fn signing_stats(&self, window: usize) -> HashMap<String, SigningStats> {
+        if window >= self.blocks.len() {
+            return self.signing_stats.clone();
+        }
+
+        let mut stats: HashMap<String, SigningStats> = HashMap::new();
+        for block_state in &self.blocks[self.blocks.len() - window..] {
+            for (validator, signed) in block_state.signatures() {
+                let entry = stats.entry(validator).or_default();
+                if signed {
+                    entry.signed += 1;
+                } else {
+                    entry.missed += 1;
+                }
+            }
+        }
+        stats
+    }
+
+    /// Validator set changes between the last two blocks
+    pub fn validator_set_changes(&self) -> Vec<ValidatorSetChange> {
+        if self.total_blocks() < 2 {
+            return vec![];
+        }
+        self.last_block().validator_set_changes(&self.prev_block())
     }
 
     pub fn get_all_transfers(&self) -> Vec<Transfer> {
@@ -140,15 +630,16 @@ impl State {
             {
                 match &inner.kind {
                     InnerKind::Transfer(transfer) => {
-                        let mut groups: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+                        let mut groups: BTreeMap<String, Vec<Amount>> = BTreeMap::new();
                         for (a, b) in &transfer.targets {
                             groups
                                 .entry(a.token.to_string())
                                 .or_default()
-                                .push(b.amount().raw_amount().as_u64());
+                                .push(b.amount());
                         }
                         for (token, amounts) in groups {
-                            let total: u64 = amounts.iter().sum();
+                            let total =
+                                Amount::sum(amounts.into_iter()).unwrap_or_else(Amount::max);
                             transfers.push(Transfer {
                                 height: block.height,
                                 id: inner.id.clone(),
@@ -161,15 +652,16 @@ impl State {
                     }
                     InnerKind::IbcMsgTransfer(IbcMessage::Transfer(msg_transfer)) => {
                         if let Some(transfer) = &msg_transfer.transfer {
-                            let mut groups: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+                            let mut groups: BTreeMap<String, Vec<Amount>> = BTreeMap::new();
                             for (a, b) in &transfer.targets {
                                 groups
                                     .entry(a.token.to_string())
                                     .or_default()
-                                    .push(b.amount().raw_amount().as_u64());
+                                    .push(b.amount());
                             }
                             for (token, amounts) in groups {
-                                let total: u64 = amounts.iter().sum();
+                                let total =
+                                    Amount::sum(amounts.into_iter()).unwrap_or_else(Amount::max);
                                 transfers.push(Transfer {
                                     height: block.height,
                                     id: inner.id.clone(),
@@ -226,3 +718,110 @@ impl State {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use namada_sdk::tendermint::{
+        account, block::Header, block::Height as TendermintHeight, evidence, hash::AppHash, Hash,
+        Time,
+    };
+
+    use super::*;
+
+    fn block_state(height: u64, timestamp: i64) -> BlockState {
+        let header = Header {
+            version: namada_sdk::tendermint::block::header::Version { block: 11, app: 0 },
+            chain_id: "namada-test".try_into().unwrap(),
+            height: TendermintHeight::try_from(height).unwrap(),
+            time: Time::from_unix_timestamp(timestamp, 0).unwrap(),
+            last_block_id: None,
+            last_commit_hash: None,
+            data_hash: None,
+            validators_hash: Hash::None,
+            next_validators_hash: Hash::None,
+            consensus_hash: Hash::None,
+            app_hash: AppHash::default(),
+            last_results_hash: None,
+            evidence_hash: None,
+            proposer_address: account::Id::new([0; 20]),
+        };
+        let block = Block {
+            height,
+            epoch: 0,
+            timestamp,
+            transactions: vec![],
+            block: namada_sdk::tendermint::Block::new(
+                header,
+                vec![],
+                evidence::List::default(),
+                None,
+            ),
+            decode_errors: vec![],
+            supply_events: vec![],
+        };
+        BlockState::new(
+            block,
+            Amount::zero(),
+            Amount::zero(),
+            vec![],
+            Arc::default(),
+            vec![],
+            HashMap::new(),
+            Liveness::default(),
+            Governance::default(),
+            Checksums::default(),
+            Parameters::default(),
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            Economics::default(),
+        )
+    }
+
+    /// Blocks at heights 1 to 10, one every 6 seconds
+    fn state() -> State {
+        let mut state = State::default();
+        for height in 1..=10 {
+            state.add_block(block_state(height, height as i64 * 6));
+        }
+        state
+    }
+
+    fn heights(blocks: &[BlockState]) -> Vec<u64> {
+        blocks
+            .iter()
+            .map(|block_state| block_state.block.height)
+            .collect()
+    }
+
+    #[test]
+    fn block_window_keeps_the_last_blocks() {
+        let state = state();
+
+        assert_eq!(heights(state.blocks_within(Window::Blocks(3))), [8, 9, 10]);
+        assert_eq!(heights(state.blocks_within(Window::Blocks(0))), [0; 0]);
+        assert_eq!(state.blocks_within(Window::Blocks(100)).len(), 10);
+    }
+
+    #[test]
+    fn time_window_keeps_the_blocks_after_its_start() {
+        let state = state();
+
+        // The window starts 18 seconds before the last block, which is excluded
+        assert_eq!(
+            heights(state.blocks_within(Window::Seconds(18))),
+            [8, 9, 10]
+        );
+        assert_eq!(heights(state.blocks_within(Window::Seconds(0))), [0; 0]);
+        assert_eq!(state.blocks_within(Window::Seconds(3_600)).len(), 10);
+    }
+
+    #[test]
+    fn empty_state_has_no_blocks_within_any_window() {
+        let state = State::default();
+
+        assert!(state.blocks_within(Window::Blocks(10)).is_empty());
+        assert!(state.blocks_within(Window::Seconds(60)).is_empty());
+    }
+}